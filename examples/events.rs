@@ -20,12 +20,13 @@ pub async fn main() -> anyhow::Result<()> {
     endpoint
         .stream(&client)
         .try_for_each_concurrent(2, |event: types::Event| async move {
-            Ok(println!("{:?}", event.name))
+            println!("{:?}", event.name);
+            Ok(())
         })
         .await?;
 
     // Search for events after October 21 2020. Sorted by tier, descending.
-    let date = Utc.ymd(2020, 10, 21).and_hms(0, 0, 0);
+    let date = Utc.with_ymd_and_hms(2020, 10, 21, 0, 0, 0).unwrap();
     let endpoint = ListEvents::builder()
         .after(date)
         .sort(EventSorting::Tier, Direction::Desc)
@@ -35,7 +36,7 @@ pub async fn main() -> anyhow::Result<()> {
         .stream(&client)
         .take(20)
         .try_for_each_concurrent(4, |event: types::Event| async move {
-            Ok(println!(
+            println!(
                 "{}: {:?}, {}",
                 event.name.unwrap_or_default(),
                 event.tier,
@@ -43,7 +44,8 @@ pub async fn main() -> anyhow::Result<()> {
                     .start_date
                     .map(|date| date.to_rfc2822())
                     .unwrap_or_default()
-            ))
+            );
+            Ok(())
         })
         .await?;
 
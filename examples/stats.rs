@@ -0,0 +1,53 @@
+use octanezsr_api::{
+    api::{
+        stats::{PlayerStats, TeamStatsByEvent},
+        AsyncQuery, Collection, CoreStat,
+    },
+    types, OctaneZsrBuilder,
+};
+
+#[tokio::main]
+pub async fn main() -> anyhow::Result<()> {
+    env_logger::init();
+
+    let client = OctaneZsrBuilder::new().build_async()?;
+
+    // Get the average score and goals of players at the event with id
+    // "5f35882d53fbbb5894b43040"
+    let endpoint = PlayerStats::builder()
+        .stat(CoreStat::Score)
+        .stat(CoreStat::Goals)
+        .event("5f35882d53fbbb5894b43040")
+        .build()?;
+    let stats: Collection<types::PlayerStatline> = endpoint.query_async(&client).await?;
+    for statline in stats.into_inner() {
+        println!(
+            "{}: {:.1} score, {:.2} goals over {} games",
+            statline.player.tag,
+            statline.average(CoreStat::Score).unwrap_or_default(),
+            statline.average(CoreStat::Goals).unwrap_or_default(),
+            statline.games.total
+        );
+    }
+
+    // Get the goals scored by team with id "6020bc70f1e4807cc70023c7", by event
+    let endpoint = TeamStatsByEvent::builder()
+        .stat(CoreStat::Goals)
+        .team("6020bc70f1e4807cc70023c7")
+        .build()?;
+    let stats: Collection<types::TeamStatline> = endpoint.query_async(&client).await?;
+    for statline in stats.into_inner() {
+        let event = statline
+            .events
+            .first()
+            .and_then(|event| event.name.clone())
+            .unwrap_or_default();
+        println!(
+            "{}: {} goals",
+            event,
+            statline.total(CoreStat::Goals).unwrap_or_default()
+        );
+    }
+
+    Ok(())
+}
@@ -4,7 +4,8 @@
 //!
 //! # Example
 //!
-//! ```rust ,no_run
+#![cfg_attr(feature = "async", doc = "```rust ,no_run")]
+#![cfg_attr(not(feature = "async"), doc = "```rust ,ignore")]
 //! use futures::{StreamExt, TryStreamExt};
 //! use octanezsr_api::{
 //!     api::{self, AsyncQuery},
@@ -71,8 +72,8 @@
 //!     // of results
 //!     let single_page_endpoint = endpoint.page().per_page(5).page(3).build();
 //!     // The wrapped endpoint can be queried like a normal endpoint, but always
-//!     // returns a `api::Root<T: Deserialize>`
-//!     let matches_page: api::Root<Match> = single_page_endpoint.query_async(&client).await?;
+//!     // returns a `api::Collection<T: Deserialize>`
+//!     let matches_page: api::Collection<Match> = single_page_endpoint.query_async(&client).await?;
 //!
 //!     println!("{:#?}", matches_page.inner[0]);
 //!
@@ -86,6 +87,7 @@ mod error;
 pub(crate) mod pagination;
 mod query;
 mod query_params;
+mod stat;
 pub(crate) mod utils;
 
 pub mod events;
@@ -98,7 +100,17 @@ pub mod stats;
 pub mod teams;
 
 pub use client::{AsyncClient, Client, RestClient};
-pub use common::{Collection, Direction, Mode, Region, Tier};
-pub use error::ApiError;
-pub use pagination::{Page, PageBuilder, Pageable, PagedEndpointExt, PagedIter};
-pub use query::{AsyncQuery, Query};
+pub use common::{
+    BestOf, Collection, Direction, ItemError, LenientCollection, Mode, Region, Tier,
+    UnknownValueError,
+};
+pub use error::{ApiError, ApiResponseError};
+pub use pagination::{
+    LenientIter, MissingPagination, Page, PageBuilder, PageCursor, Pageable, PagedEndpointExt,
+    PagedIter, PagedStream, PagesIter,
+};
+pub use query::{AsyncOptionalQuery, AsyncQuery, OptionalQuery, Query};
+pub use stat::{
+    AdvancedStat, BallStat, BoostStat, CoreStat, DemoStat, MovementStat, PositioningStat, Stat,
+};
+pub use utils::{DataTypeError, ResponseError};
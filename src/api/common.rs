@@ -1,5 +1,11 @@
-use serde::{Deserialize, Serialize};
-use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::{
+    fmt::{self, Display},
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
+use thiserror::Error;
 
 /// The root type returned by queries that return a collection endpoint
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -13,6 +19,7 @@ pub struct Collection<T> {
     #[serde(alias = "teams")]
     #[serde(alias = "records")]
     #[serde(alias = "participants")]
+    #[serde(alias = "stats")]
     pub inner: Vec<T>,
     /// Pagination metadata, if present
     #[serde(flatten)]
@@ -26,6 +33,106 @@ impl<T> Collection<T> {
     }
 }
 
+/// A [`Collection`] whose elements are deserialized one by one, skipping
+/// elements that fail to deserialize instead of failing the whole collection
+///
+/// Like a [`Collection`], it can be the result of querying a [`Page`]. To
+/// iterate over the results of several pages this way, use
+/// [`iter_lenient`](crate::api::PagedEndpointExt::iter_lenient) or
+/// [`stream_lenient`](crate::api::PagedEndpointExt::stream_lenient).
+///
+/// [`Page`]: crate::api::Page
+///
+/// # Example
+///
+/// ```rust
+/// use octanezsr_api::{api::LenientCollection, types::Team};
+///
+/// let json = r#"{"teams": [{"_id": "1", "name": "G2"}, {"_id": "2", "name": 2}]}"#;
+/// let teams: LenientCollection<Team> = serde_json::from_str(json)?;
+///
+/// assert_eq!(teams.inner.len(), 1);
+/// assert_eq!(teams.errors[0].path, "[1].name");
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Debug, Deserialize)]
+#[serde(
+    from = "Collection<serde_json::Value>",
+    bound(deserialize = "T: DeserializeOwned")
+)]
+pub struct LenientCollection<T> {
+    /// The elements that deserialized successfully
+    pub inner: Vec<T>,
+    /// The elements that failed to deserialize
+    pub errors: Vec<ItemError>,
+    /// Pagination metadata, if present
+    pub pagination: Option<Pagination>,
+}
+
+impl<T> LenientCollection<T> {
+    /// Consumes the [`LenientCollection`] returning the valid elements.
+    pub fn into_inner(self) -> Vec<T> {
+        self.inner
+    }
+}
+
+impl<T> From<Collection<serde_json::Value>> for LenientCollection<T>
+where
+    T: DeserializeOwned,
+{
+    fn from(value: Collection<serde_json::Value>) -> Self {
+        let mut inner = Vec::with_capacity(value.inner.len());
+        let mut errors = Vec::new();
+        for (index, value) in value.inner.into_iter().enumerate() {
+            match ItemError::deserialize(index, value) {
+                Ok(item) => inner.push(item),
+                Err(err) => errors.push(err),
+            }
+        }
+
+        Self {
+            inner,
+            errors,
+            pagination: value.pagination,
+        }
+    }
+}
+
+/// An element of a collection that failed to deserialize
+#[derive(Debug, Error)]
+#[error("invalid element at `{path}`: {source}")]
+pub struct ItemError {
+    /// Index of the element in its page
+    pub index: usize,
+    /// Path to the offending value. Example: `[3].blue.players[0].stats`
+    pub path: String,
+    /// Source of the error
+    pub source: serde_json::Error,
+    /// The raw element
+    pub value: serde_json::Value,
+}
+
+impl ItemError {
+    /// Deserialize the element at `index` of a collection
+    pub(crate) fn deserialize<T>(index: usize, value: serde_json::Value) -> Result<T, Self>
+    where
+        T: DeserializeOwned,
+    {
+        serde_path_to_error::deserialize(&value).map_err(|err| {
+            let path = match err.path().to_string() {
+                path if path == "." => format!("[{}]", index),
+                path => format!("[{}].{}", index, path),
+            };
+            Self {
+                index,
+                path,
+                source: err.into_inner(),
+                value,
+            }
+        })
+    }
+}
+
 /// Pagination metadata
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -38,59 +145,291 @@ pub struct Pagination {
     pub page_size: usize,
 }
 
-/// Event tier
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
-#[serde(rename_all = "PascalCase")]
-pub enum Tier {
-    S,
-    A,
-    B,
-    C,
-    D,
-    Monthly,
-    Weekly,
-    #[serde(rename = "Show Match")]
-    ShowMatch,
-    Qualifier,
-}
-
-/// Event region
-#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
-pub enum Region {
-    #[serde(rename = "NA")]
-    NorthAmerica,
-    #[serde(rename = "EU")]
-    Europe,
-    #[serde(rename = "OCE")]
-    Oceania,
-    #[serde(rename = "SAM")]
-    SouthAmerica,
-    #[serde(rename = "ASIA")]
-    Asia,
-    #[serde(rename = "ME")]
-    MiddleEast,
-    #[serde(rename = "INT")]
-    International,
-    #[serde(rename = "AF")]
-    Africa,
-}
-
-/// Event mode
-#[derive(Debug, Serialize_repr, Deserialize_repr, PartialEq, Eq, Clone, Copy)]
-#[repr(u8)]
-pub enum Mode {
-    One = 1,
-    Two = 2,
-    Three = 3,
-}
-
-/// Match format
-#[derive(Debug, Serialize_repr, Deserialize_repr, PartialEq, Eq, Clone, Copy)]
-#[repr(u8)]
-pub enum BestOf {
-    Three = 3,
-    Five = 5,
-    Seven = 7,
+/// Error parsing a value unknown to this crate, e.g. `"NAA"` as a [`Region`]
+///
+/// Unknown values can still be represented by the `Other` variants, e.g. with
+/// `Region::from("LATAM")`.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("unknown {kind} `{value}`")]
+pub struct UnknownValueError {
+    /// Name of the type being parsed. Example: `"Region"`
+    pub kind: &'static str,
+    /// The unknown value
+    pub value: String,
+}
+
+/// Define an enum of the values of a string field, with an `Other` variant
+/// that round-trips values unknown to this crate
+///
+/// Known values are matched ignoring ASCII case, and an `Other` holding a
+/// known value is equal to that value's variant.
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        $name:ident {
+            $($(#[$doc:meta])* $variant:ident => $wire:literal,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Eq)]
+        pub enum $name {
+            $($(#[$doc])* $variant,)*
+            /// A value unknown to this crate
+            Other(String),
+        }
+
+        impl $name {
+            /// Value of the field, as used by the API
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $wire,)*
+                    $name::Other(value) => Self::known(value).map_or(value, |known| known.as_str()),
+                }
+            }
+
+            /// The variant of a known value, ignoring ASCII case
+            fn known(value: &str) -> Option<&'static Self> {
+                $(if value.eq_ignore_ascii_case($wire) {
+                    return Some(&$name::$variant);
+                })*
+                None
+            }
+        }
+
+        impl From<&str> for $name {
+            /// Convert a value, ignoring ASCII case. Unknown values convert
+            /// to `Other`.
+            fn from(value: &str) -> Self {
+                Self::known(value)
+                    .cloned()
+                    .unwrap_or_else(|| $name::Other(value.to_owned()))
+            }
+        }
+
+        impl From<String> for $name {
+            /// Convert a value, ignoring ASCII case. Unknown values convert
+            /// to `Other`.
+            fn from(value: String) -> Self {
+                Self::known(&value).cloned().unwrap_or($name::Other(value))
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = UnknownValueError;
+
+            /// Parse a known value, ignoring ASCII case
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                Self::known(value).cloned().ok_or_else(|| UnknownValueError {
+                    kind: stringify!($name),
+                    value: value.to_owned(),
+                })
+            }
+        }
+
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.as_str() == other.as_str()
+            }
+        }
+
+        impl Hash for $name {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.as_str().hash(state)
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                String::deserialize(deserializer).map($name::from)
+            }
+        }
+    };
+}
+
+/// Define an enum of the values of a numeric field, with an `Other` variant
+/// that round-trips values unknown to this crate
+///
+/// An `Other` holding a known value is equal to that value's variant.
+macro_rules! number_enum {
+    (
+        $(#[$meta:meta])*
+        $name:ident {
+            $($(#[$doc:meta])* $variant:ident => $wire:literal,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, Eq)]
+        pub enum $name {
+            $($(#[$doc])* $variant,)*
+            /// A value unknown to this crate
+            Other(u8),
+        }
+
+        impl $name {
+            /// Value of the field, as used by the API
+            pub fn as_u8(&self) -> u8 {
+                match self {
+                    $($name::$variant => $wire,)*
+                    $name::Other(value) => *value,
+                }
+            }
+        }
+
+        impl From<u8> for $name {
+            /// Convert a value. Unknown values convert to `Other`.
+            fn from(value: u8) -> Self {
+                match value {
+                    $($wire => $name::$variant,)*
+                    value => $name::Other(value),
+                }
+            }
+        }
+
+        impl From<$name> for u8 {
+            fn from(value: $name) -> Self {
+                value.as_u8()
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = UnknownValueError;
+
+            /// Parse a known value
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                match value.trim().parse::<u8>().map($name::from) {
+                    Ok($name::Other(_)) | Err(_) => Err(UnknownValueError {
+                        kind: stringify!($name),
+                        value: value.to_owned(),
+                    }),
+                    Ok(known) => Ok(known),
+                }
+            }
+        }
+
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.as_u8() == other.as_u8()
+            }
+        }
+
+        impl Hash for $name {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.as_u8().hash(state)
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.as_u8())
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serializer.serialize_u8(self.as_u8())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                u8::deserialize(deserializer).map($name::from)
+            }
+        }
+    };
+}
+
+string_enum! {
+    /// Event tier
+    Tier {
+        /// S-tier
+        S => "S",
+        /// A-tier
+        A => "A",
+        /// B-tier
+        B => "B",
+        /// C-tier
+        C => "C",
+        /// D-tier
+        D => "D",
+        /// Monthly event
+        Monthly => "Monthly",
+        /// Weekly event
+        Weekly => "Weekly",
+        /// Show match
+        ShowMatch => "Show Match",
+        /// Qualifier
+        Qualifier => "Qualifier",
+    }
+}
+
+string_enum! {
+    /// Event region
+    Region {
+        /// North America (`NA`)
+        NorthAmerica => "NA",
+        /// Europe (`EU`)
+        Europe => "EU",
+        /// Oceania (`OCE`)
+        Oceania => "OCE",
+        /// South America (`SAM`)
+        SouthAmerica => "SAM",
+        /// Asia (`ASIA`)
+        Asia => "ASIA",
+        /// Middle East (`ME`)
+        MiddleEast => "ME",
+        /// International (`INT`)
+        International => "INT",
+        /// Africa (`AF`)
+        Africa => "AF",
+    }
+}
+
+number_enum! {
+    /// Event mode
+    Mode {
+        /// 1v1
+        One => 1,
+        /// 2v2
+        Two => 2,
+        /// 3v3
+        Three => 3,
+    }
+}
+
+number_enum! {
+    /// Match format
+    BestOf {
+        /// Best of three
+        Three => 3,
+        /// Best of five
+        Five => 5,
+        /// Best of seven
+        Seven => 7,
+    }
 }
 
 /// Sort direction
@@ -112,3 +451,100 @@ impl Direction {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Score {
+        score: f64,
+    }
+
+    #[test]
+    fn strict_collection_path() {
+        let json = json!({"games": [{"score": 1}, {"score": "high"}]});
+        let err = serde_path_to_error::deserialize::<_, Collection<Score>>(&json).unwrap_err();
+
+        assert_eq!(err.path().to_string(), "games[1].score");
+    }
+
+    #[test]
+    fn lenient_collection_skips_invalid() {
+        let json = json!({
+            "games": [{"score": 1}, {"score": "high"}, {}, {"score": 2.5}],
+            "page": 1,
+            "perPage": 4,
+            "pageSize": 4,
+        });
+        let games: LenientCollection<Score> = serde_json::from_value(json).unwrap();
+
+        assert_eq!(games.inner, [Score { score: 1.0 }, Score { score: 2.5 }]);
+        assert_eq!(games.pagination.map(|p| p.page_size), Some(4));
+
+        let errors: Vec<_> = games
+            .errors
+            .iter()
+            .map(|err| (err.index, err.path.as_str()))
+            .collect();
+        assert_eq!(errors, [(1, "[1].score"), (2, "[2]")]);
+        assert_eq!(games.errors[0].value, json!({"score": "high"}));
+    }
+
+    #[test]
+    fn unknown_values_round_trip() {
+        let value = json!([["NA", "LATAM"], ["Show Match", "Premier"], [3, 4]]);
+        let (regions, tiers, modes): (Vec<Region>, Vec<Tier>, Vec<Mode>) =
+            serde_json::from_value(value.clone()).unwrap();
+
+        assert_eq!(
+            regions,
+            [Region::NorthAmerica, Region::Other("LATAM".into())]
+        );
+        assert_eq!(tiers, [Tier::ShowMatch, Tier::Other("Premier".into())]);
+        assert_eq!(modes, [Mode::Three, Mode::Other(4)]);
+        assert_eq!(json!([regions, tiers, modes]), value);
+    }
+
+    #[test]
+    fn parse_and_display() {
+        assert_eq!("na".parse(), Ok(Region::NorthAmerica));
+        assert_eq!("S".parse(), Ok(Tier::S));
+        assert_eq!("show match".parse(), Ok(Tier::ShowMatch));
+        assert_eq!("5".parse(), Ok(BestOf::Five));
+        assert!("five".parse::<BestOf>().is_err());
+        assert!("4".parse::<Mode>().is_err());
+        assert_eq!(
+            "NAA".parse::<Region>(),
+            Err(UnknownValueError {
+                kind: "Region",
+                value: "NAA".into()
+            })
+        );
+        assert_eq!(Region::from("NAA"), Region::Other("NAA".into()));
+
+        assert_eq!(Region::Oceania.to_string(), "OCE");
+        assert_eq!(Tier::Other("Premier".into()).to_string(), "Premier");
+        assert_eq!(Mode::Two.to_string(), "2");
+    }
+
+    #[test]
+    fn other_known_values_are_normalized() {
+        use std::collections::HashSet;
+
+        assert_eq!(Region::Other("NA".into()), Region::NorthAmerica);
+        assert_eq!(Region::Other("na".into()), Region::NorthAmerica);
+        assert_ne!(Region::Other("LATAM".into()), Region::Other("latam".into()));
+        assert_eq!(Tier::Other("show match".into()).as_str(), "Show Match");
+        assert_eq!(Mode::Other(3), Mode::Three);
+        assert_eq!(json!(Region::Other("eu".into())), json!("EU"));
+
+        let regions: HashSet<_> = [Region::NorthAmerica, Region::Other("NA".into())].into();
+        assert_eq!(regions.len(), 1);
+
+        let region: Region = serde_json::from_value(json!("na")).unwrap();
+        assert!(matches!(region, Region::NorthAmerica));
+    }
+}
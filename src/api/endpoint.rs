@@ -41,7 +41,8 @@ where
 
         let rsp = client.rest(http_req, body)?;
 
-        deserialize_response::<_, C>(rsp).map_err(|e| ApiError::from_http_response(e, url))
+        deserialize_response(rsp)
+            .map_err(|e| ApiError::from_http_response(e, &self.endpoint(), url))
     }
 }
 
@@ -58,6 +59,7 @@ where
 
         let rsp = client.rest_async(http_req, body).await?;
 
-        deserialize_response::<_, C>(rsp).map_err(|e| ApiError::from_http_response(e, url))
+        deserialize_response(rsp)
+            .map_err(|e| ApiError::from_http_response(e, &self.endpoint(), url))
     }
 }
@@ -21,31 +21,120 @@ where
     #[error("url parse error: {0}")]
     Parse(#[from] url::ParseError),
     /// Error in HTTP response
-    #[error("Error in HTTP response for url {url}: {source}")]
-    Response {
-        /// Source of the error
-        source: ResponseError,
-        /// URL of the error
-        url: http::Uri,
+    #[error(transparent)]
+    Response(Box<ApiResponseError>),
+    /// A page of results did not contain pagination metadata
+    #[error("missing pagination metadata for page {page} of {endpoint}")]
+    MissingPagination {
+        /// Path of the paged endpoint, with a leading slash. Example:
+        /// `"/events"`
+        endpoint: String,
+        /// Requested page
+        page: usize,
     },
 }
 
+/// An error in the HTTP response from an endpoint
+#[derive(Debug, Error)]
+#[error("Error in HTTP response from {endpoint} for url {url}: {source}")]
+pub struct ApiResponseError {
+    /// Source of the error
+    pub source: ResponseError,
+    /// Path of the endpoint, with a leading slash. Example: `"/events"`
+    pub endpoint: String,
+    /// URL of the error
+    pub url: http::Uri,
+}
+
 impl<E> ApiError<E>
 where
     E: Error + Send + Sync + 'static,
 {
+    /// Whether the requested resource does not exist, e.g. an unknown id
+    pub fn is_not_found(&self) -> bool {
+        matches!(
+            self,
+            Self::Response(err) if matches!(err.source, ResponseError::NotFound { .. })
+        )
+    }
+
     pub(crate) fn client(source: E) -> Self {
         Self::Client(source)
     }
 
-    pub(crate) fn from_http_response(source: ResponseError, url: http::Uri) -> Self {
-        Self::Response { source, url }
+    pub(crate) fn from_http_response(
+        source: ResponseError,
+        endpoint: &str,
+        url: http::Uri,
+    ) -> Self {
+        Self::Response(Box::new(ApiResponseError {
+            source,
+            endpoint: endpoint_path(endpoint),
+            url,
+        }))
+    }
+
+    pub(crate) fn missing_pagination(endpoint: &str, page: usize) -> Self {
+        Self::MissingPagination {
+            endpoint: endpoint_path(endpoint),
+            page,
+        }
+    }
+
+    pub(crate) fn map_client<F, O>(self, f: F) -> ApiError<O>
+    where
+        F: FnOnce(E) -> O,
+        O: Error + Send + Sync + 'static,
+    {
+        match self {
+            Self::Body(source) => ApiError::Body(source),
+            Self::Client(source) => ApiError::Client(f(source)),
+            Self::Parse(source) => ApiError::Parse(source),
+            Self::Response(err) => ApiError::Response(err),
+            Self::MissingPagination { endpoint, page } => {
+                ApiError::MissingPagination { endpoint, page }
+            }
+        }
     }
 }
 
+/// Endpoint paths are relative to the base URL, with or without a leading
+/// slash. Errors always report them with one.
+fn endpoint_path(endpoint: &str) -> String {
+    format!("/{}", endpoint.trim_start_matches('/'))
+}
+
 #[derive(Debug, Error)]
 pub enum BodyError {
-    /// Error serializing form data
-    #[error("URL encoding error: {0}")]
-    UrlEncoding(#[from] serde_urlencoded::ser::Error),
+    /// Error serializing query parameters
+    #[error("serialization error: {0}")]
+    Serialize(#[from] serde_json::Error),
+    /// A value can not be represented as query parameters
+    #[error("unsupported query parameter value: {0}")]
+    Unsupported(String),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mock::MockError;
+
+    #[test]
+    fn endpoint_paths_have_leading_slash() {
+        for endpoint in ["events", "/events"] {
+            let err = ApiError::<MockError>::from_http_response(
+                ResponseError::NotFound {
+                    message: String::new(),
+                },
+                endpoint,
+                http::Uri::from_static("https://zsr.octane.gg/events"),
+            );
+            assert!(matches!(err, ApiError::Response(err) if err.endpoint == "/events"));
+
+            let err = ApiError::<MockError>::missing_pagination(endpoint, 2);
+            assert!(
+                matches!(err, ApiError::MissingPagination { endpoint, .. } if endpoint == "/events")
+            );
+        }
+    }
 }
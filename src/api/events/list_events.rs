@@ -54,12 +54,18 @@ impl From<EventSorting> for &'static str {
 pub struct ListEvents<'a> {
     #[doc = "A portion of the even name. Example: `\"RLCS\"`"]
     name: Option<Cow<'a, str>>,
-    #[doc = "Event tier. Example `Tier::S`"]
-    tier: Option<Tier>,
-    #[doc = "Event region. Example: `Region::NA`"]
-    region: Option<Region>,
-    #[doc = "Event mode. Example: `Mode::Three`"]
-    mode: Option<Mode>,
+    #[doc = "Event tiers. Example `Tier::S`"]
+    #[serde(rename = "tier")]
+    #[builder(setter(each(name = "tier")))]
+    tiers: Vec<Tier>,
+    #[doc = "Event regions. Example: `Region::NA`"]
+    #[serde(rename = "region")]
+    #[builder(setter(each(name = "region")))]
+    regions: Vec<Region>,
+    #[doc = "Event modes. Example: `Mode::Three`"]
+    #[serde(rename = "mode")]
+    #[builder(setter(each(name = "mode")))]
+    modes: Vec<Mode>,
     #[doc = "Event group. Example: `\"rlcsx\"`"]
     group: Option<Cow<'a, str>>,
     #[doc = "Filter events before this date. Example: `\"2016-12-03\".parse()`"]
@@ -95,7 +101,7 @@ impl ListEventsBuilder<'_> {
 
 impl Endpoint for ListEvents<'_> {
     fn endpoint(&self) -> Cow<'static, str> {
-        "events".into()
+        "/events".into()
     }
 
     fn query_parameters(&self) -> Result<QueryParams<'_>, BodyError> {
@@ -93,12 +93,18 @@ pub struct ListGames<'a> {
     match_id: Option<MatchId<'a>>,
     #[doc = "Is stage a qualifier. Example: `true`"]
     qualifier: Option<bool>,
-    #[doc = "An event tier. Example: `Tier::S`"]
-    tier: Option<Tier>,
-    #[doc = "An event region. Example: `Region::NA`"]
-    region: Option<Region>,
-    #[doc = "An event mode. Example: `Mode::Three`"]
-    mode: Option<Mode>,
+    #[doc = "Event tiers. Example: `Tier::S`"]
+    #[serde(rename = "tier")]
+    #[builder(setter(each(name = "tier")))]
+    tiers: Vec<Tier>,
+    #[doc = "Event regions. Example: `Region::NA`"]
+    #[serde(rename = "region")]
+    #[builder(setter(each(name = "region")))]
+    regions: Vec<Region>,
+    #[doc = "Event modes. Example: `Mode::Three`"]
+    #[serde(rename = "mode")]
+    #[builder(setter(each(name = "mode")))]
+    modes: Vec<Mode>,
     #[doc = "An event group. Example: `\"rlcsx\"`"]
     group: Option<Cow<'a, str>>,
     #[doc = "Filter matches before this date. Example: `\"2016-12-03\".parse()`"]
@@ -107,10 +113,14 @@ pub struct ListGames<'a> {
     after: Option<DateTime<Utc>>,
     #[doc = "A match format. Example: `BestOf::Five`"]
     best_of: Option<BestOf>,
-    #[doc = "A player id. Example: `\"5f3d8fdd95f40596eae23d97\"`"]
-    player: Option<PlayerId<'a>>,
-    #[doc = "A team id. Example: `\"6020bc70f1e4807cc70023c7\"`"]
-    team: Option<TeamId<'a>>,
+    #[doc = "Player IDs. Example: `\"5f3d8fdd95f40596eae23d97\"`"]
+    #[serde(rename = "player")]
+    #[builder(setter(each(name = "player", into)))]
+    players: Vec<PlayerId<'a>>,
+    #[doc = "Team IDs. Example: `\"6020bc70f1e4807cc70023c7\"`"]
+    #[serde(rename = "team")]
+    #[builder(setter(each(name = "team", into)))]
+    teams: Vec<TeamId<'a>>,
     #[builder(setter(custom))]
     #[serde(serialize_with = "crate::api::utils::serialize_as_colon_separated")]
     sort: Option<(GamesSorting, Direction)>,
@@ -89,12 +89,18 @@ pub struct ListMatches<'a> {
     stage: Option<StageId>,
     #[doc = "Is stage a qualifier. Example: `true`"]
     qualifier: Option<bool>,
-    #[doc = "An event tier. Example: `Tier::S`"]
-    tier: Option<Tier>,
-    #[doc = "An event region. Example: `Region::NA`"]
-    region: Option<Region>,
-    #[doc = "An event mode. Example: `Mode::Three`"]
-    mode: Option<Mode>,
+    #[doc = "Event tiers. Example: `Tier::S`"]
+    #[serde(rename = "tier")]
+    #[builder(setter(each(name = "tier")))]
+    tiers: Vec<Tier>,
+    #[doc = "Event regions. Example: `Region::NA`"]
+    #[serde(rename = "region")]
+    #[builder(setter(each(name = "region")))]
+    regions: Vec<Region>,
+    #[doc = "Event modes. Example: `Mode::Three`"]
+    #[serde(rename = "mode")]
+    #[builder(setter(each(name = "mode")))]
+    modes: Vec<Mode>,
     #[doc = "An event group. Example: `\"rlcsx\"`"]
     group: Option<Cow<'a, str>>,
     #[doc = "Filter events before this date. Example: `\"2016-12-03\".parse()`"]
@@ -107,10 +113,14 @@ pub struct ListMatches<'a> {
     reverse_sweep: Option<bool>,
     #[doc = "Is match a reverse sweep attempt. Example: `true`"]
     reverse_sweep_attempt: Option<bool>,
-    #[doc = "A player id. Example: `\"5f3d8fdd95f40596eae23d97\"`"]
-    player: Option<PlayerId<'a>>,
-    #[doc = "A team id. Example: `\"6020bc70f1e4807cc70023c7\"`"]
-    team: Option<TeamId<'a>>,
+    #[doc = "Player IDs. Example: `\"5f3d8fdd95f40596eae23d97\"`"]
+    #[serde(rename = "player")]
+    #[builder(setter(each(name = "player", into)))]
+    players: Vec<PlayerId<'a>>,
+    #[doc = "Team IDs. Example: `\"6020bc70f1e4807cc70023c7\"`"]
+    #[serde(rename = "team")]
+    #[builder(setter(each(name = "team", into)))]
+    teams: Vec<TeamId<'a>>,
     #[builder(setter(custom))]
     #[serde(serialize_with = "crate::api::utils::serialize_as_colon_separated")]
     sort: Option<(MatchesSorting, Direction)>,
@@ -147,3 +157,72 @@ impl Endpoint for ListMatches<'_> {
 }
 
 impl Pageable for ListMatches<'_> {}
+
+#[cfg(test)]
+mod test {
+    use chrono::TimeZone;
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::{
+        api::Query,
+        mock::{Expectation, MockClient},
+    };
+
+    #[test]
+    fn query_parameters() {
+        let client = MockClient::new();
+        client.expect(Expectation::get("/matches").json(&json!({"matches": []})));
+
+        let endpoint = ListMatches::builder()
+            .event("5f35882d53fbbb5894b43040")
+            .tier(Tier::S)
+            .region(Region::NorthAmerica)
+            .mode(Mode::Three)
+            .after(Utc.with_ymd_and_hms(2020, 10, 21, 0, 0, 0).unwrap())
+            .reverse_sweep(true)
+            .sort(MatchesSorting::Date, Direction::Desc)
+            .build()
+            .unwrap();
+        let _: Value = endpoint.query(&client).unwrap();
+
+        client.assert_requests(&[
+            "/matches?event=5f35882d53fbbb5894b43040&tier=S&region=NA&mode=3\
+             &after=2020-10-21T00%3A00%3A00Z&reverseSweep=true&sort=date%3Adesc",
+        ]);
+    }
+
+    #[test]
+    fn repeated_query_parameters() {
+        let client = MockClient::new();
+        client.expect(Expectation::get("/matches").json(&json!({"matches": []})));
+
+        let endpoint = ListMatches::builder()
+            .tier(Tier::S)
+            .tier(Tier::A)
+            .regions(vec![Region::NorthAmerica, Region::Europe])
+            .team("6020bc70f1e4807cc70023c7")
+            .team("6020bc70f1e4807cc70023a5")
+            .build()
+            .unwrap();
+        let _: Value = endpoint.query(&client).unwrap();
+
+        client.assert_requests(&["/matches?tier=S&tier=A&region=NA&region=EU\
+             &team=6020bc70f1e4807cc70023c7&team=6020bc70f1e4807cc70023a5"]);
+    }
+
+    #[test]
+    fn empty_query_parameters() {
+        let client = MockClient::new();
+        client.expect(
+            Expectation::get("/matches")
+                .query("")
+                .json(&json!({"matches": []})),
+        );
+
+        let endpoint = ListMatches::builder().build().unwrap();
+        let _: Value = endpoint.query(&client).unwrap();
+
+        client.assert_requests(&["/matches"]);
+    }
+}
@@ -1,10 +1,18 @@
+use std::{
+    error::Error,
+    fmt,
+    marker::PhantomData,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
 use async_trait::async_trait;
-use futures::{stream::BoxStream, StreamExt, TryStreamExt};
-use http::{header, Request};
-use serde::{de::DeserializeOwned, Serialize};
+use futures::{stream::BoxStream, Stream, StreamExt, TryStreamExt};
+use http::{header, request::Builder as RequestBuilder, Request};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 use super::{
-    common::Collection,
+    common::{Collection, ItemError, LenientCollection},
     endpoint::Endpoint,
     query::{AsyncQuery, Query},
     utils::{deserialize_response, url_to_http_uri},
@@ -22,11 +30,90 @@ pub trait PagedEndpointExt<'a, E> {
         C: Client,
         T: DeserializeOwned;
 
+    /// Create an Iterator over the results of the paginated endpoint,
+    /// resuming from a saved [`PageCursor`]
+    fn iter_from<T, C>(&'a self, client: &'a C, cursor: PageCursor) -> PagedIter<'a, E, C, T>
+    where
+        C: Client,
+        T: DeserializeOwned;
+
     /// `GET` a single page of results for the paginated endpoint
     fn page(&'a self) -> PageBuilder<'a, E>;
 
+    /// Create an Iterator over the pages of the paginated endpoint
+    ///
+    /// Use [`PageBuilder::pages`] to choose the page size or first page.
+    fn pages<T, C>(&'a self, client: &'a C) -> PagesIter<'a, E, C, T>
+    where
+        C: Client,
+        T: DeserializeOwned;
+
+    /// Create an async Stream over the pages of the paginated endpoint
+    ///
+    /// Use [`PageBuilder::pages_stream`] to choose the page size or first
+    /// page.
+    fn pages_stream<T, C>(
+        &'a self,
+        client: &'a C,
+    ) -> BoxStream<'a, Result<Collection<T>, ApiError<C::Error>>>
+    where
+        T: DeserializeOwned + Send + 'static,
+        C: AsyncClient + Sync,
+        E: Send + Sync;
+
     /// Create an async Stream over the results of the paginated endpoint
-    fn stream<T, C>(&'a self, client: &'a C) -> BoxStream<'a, Result<T, ApiError<C::Error>>>
+    fn stream<T, C>(&'a self, client: &'a C) -> PagedStream<'a, T, C::Error>
+    where
+        T: DeserializeOwned + Send + 'static,
+        C: AsyncClient + Sync,
+        E: Send + Sync;
+
+    /// Create an async Stream over the results of the paginated endpoint,
+    /// resuming from a saved [`PageCursor`]
+    fn stream_from<T, C>(
+        &'a self,
+        client: &'a C,
+        cursor: PageCursor,
+    ) -> PagedStream<'a, T, C::Error>
+    where
+        T: DeserializeOwned + Send + 'static,
+        C: AsyncClient + Sync,
+        E: Send + Sync;
+
+    /// Create an async Stream over the results of the paginated endpoint,
+    /// keeping up to `concurrency` page requests in flight
+    ///
+    /// Results are still yielded in page order. The stream ends after the
+    /// first short or empty page, or after the first error.
+    fn stream_concurrent<T, C>(
+        &'a self,
+        client: &'a C,
+        concurrency: usize,
+    ) -> PagedStream<'a, T, C::Error>
+    where
+        T: DeserializeOwned + Send + 'static,
+        C: AsyncClient + Sync,
+        E: Send + Sync;
+
+    /// Create an Iterator over the results of the paginated endpoint,
+    /// deserializing each result independently
+    ///
+    /// A result that fails to deserialize is yielded as an [`ItemError`]
+    /// holding the raw result, instead of failing its whole page.
+    fn iter_lenient<T, C>(&'a self, client: &'a C) -> LenientIter<'a, E, C, T>
+    where
+        C: Client,
+        T: DeserializeOwned;
+
+    /// Create an async Stream over the results of the paginated endpoint,
+    /// deserializing each result independently
+    ///
+    /// A result that fails to deserialize is yielded as an [`ItemError`]
+    /// holding the raw result, instead of failing its whole page.
+    fn stream_lenient<T, C>(
+        &'a self,
+        client: &'a C,
+    ) -> PagedStream<'a, Result<T, ItemError>, C::Error>
     where
         T: DeserializeOwned + Send + 'static,
         C: AsyncClient + Sync,
@@ -42,46 +129,431 @@ where
         C: Client,
         T: DeserializeOwned,
     {
-        PagedIter::new(self, client)
+        PagedIter::new(
+            self,
+            client,
+            PageCursor::default(),
+            None,
+            MissingPagination::default(),
+        )
+    }
+
+    fn iter_from<T, C>(&'a self, client: &'a C, cursor: PageCursor) -> PagedIter<'a, E, C, T>
+    where
+        C: Client,
+        T: DeserializeOwned,
+    {
+        PagedIter::new(self, client, cursor, None, MissingPagination::default())
     }
 
     fn page(&'a self) -> PageBuilder<'a, E> {
         PageBuilder::new(self)
     }
 
-    fn stream<T, C>(&'a self, client: &'a C) -> BoxStream<'a, Result<T, ApiError<C::Error>>>
+    fn pages<T, C>(&'a self, client: &'a C) -> PagesIter<'a, E, C, T>
+    where
+        C: Client,
+        T: DeserializeOwned,
+    {
+        self.page().pages(client)
+    }
+
+    fn pages_stream<T, C>(
+        &'a self,
+        client: &'a C,
+    ) -> BoxStream<'a, Result<Collection<T>, ApiError<C::Error>>>
     where
         T: DeserializeOwned + Send + 'static,
         C: AsyncClient + Sync,
         E: Send + Sync,
     {
-        //FIXME: Set this back to 1
-        futures::stream::try_unfold(Some(1), move |state| async move {
-            let Some(page) = state else {
-                return Ok::<_, ApiError<C::Error>>(None);
-            };
+        self.page().pages_stream(client)
+    }
+
+    fn stream<T, C>(&'a self, client: &'a C) -> PagedStream<'a, T, C::Error>
+    where
+        T: DeserializeOwned + Send + 'static,
+        C: AsyncClient + Sync,
+        E: Send + Sync,
+    {
+        self.stream_from(client, PageCursor::default())
+    }
+
+    fn stream_from<T, C>(
+        &'a self,
+        client: &'a C,
+        cursor: PageCursor,
+    ) -> PagedStream<'a, T, C::Error>
+    where
+        T: DeserializeOwned + Send + 'static,
+        C: AsyncClient + Sync,
+        E: Send + Sync,
+    {
+        PagedStream::new(self, client, cursor, None, MissingPagination::default())
+    }
+
+    fn stream_concurrent<T, C>(
+        &'a self,
+        client: &'a C,
+        concurrency: usize,
+    ) -> PagedStream<'a, T, C::Error>
+    where
+        T: DeserializeOwned + Send + 'static,
+        C: AsyncClient + Sync,
+        E: Send + Sync,
+    {
+        let start = PageCursor::default();
+        let paged = self;
+        let paginator = Paginator::new(start, None, MissingPagination::default());
+        let inner = futures::stream::iter(start.page..)
+            .map(move |page| async move {
+                let cursor = PageCursor { page, ..start };
+                let page = cursor.page(self).query_async(client).await;
+                page.map(|page| (cursor, page))
+            })
+            .buffered(concurrency.max(1))
+            .scan(
+                paginator,
+                move |paginator, page: Result<(PageCursor, Collection<T>), _>| {
+                    // Pages are requested ahead of the paginator, so responses
+                    // past the end of the results are dropped here
+                    if paginator.next_page().is_none() {
+                        return futures::future::ready(None);
+                    }
+
+                    let page = match page {
+                        Ok((cursor, mut page)) => paginator
+                            .advance(paged, cursor, &mut page)
+                            .map(|_| futures::stream::iter(cursor.tag(page.inner).map(Ok))),
+                        Err(err) => {
+                            paginator.fail();
+                            Err(err)
+                        }
+                    };
+
+                    futures::future::ready(Some(page))
+                },
+            )
+            .try_flatten()
+            .boxed();
+
+        PagedStream::from_inner(inner, start)
+    }
 
-            let page = Page::builder(self).page(page).build();
-            let page = page.query_async(client).await?;
+    fn iter_lenient<T, C>(&'a self, client: &'a C) -> LenientIter<'a, E, C, T>
+    where
+        C: Client,
+        T: DeserializeOwned,
+    {
+        LenientIter::new(self.iter(client))
+    }
 
-            let pagination = page.pagination.expect("Missing pagination info!");
+    fn stream_lenient<T, C>(
+        &'a self,
+        client: &'a C,
+    ) -> PagedStream<'a, Result<T, ItemError>, C::Error>
+    where
+        T: DeserializeOwned + Send + 'static,
+        C: AsyncClient + Sync,
+        E: Send + Sync,
+    {
+        let values: PagedStream<'a, serde_json::Value, C::Error> = self.stream(client);
+        values.lenient()
+    }
+}
+
+/// What paged iterators and streams do when a page of results has no
+/// pagination metadata
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissingPagination {
+    /// Keep requesting pages until an empty page is returned
+    #[default]
+    UntilEmpty,
+    /// Fail with [`ApiError::MissingPagination`]
+    Error,
+}
 
-            if page.inner.is_empty() {
-                Ok(None)
-            } else {
-                let next_state = if pagination.page_size < pagination.per_page {
-                    None
+/// Position in the results of a paginated endpoint
+///
+/// A cursor can be saved while iterating, and passed to [`iter_from`] or
+/// [`stream_from`] to resume from the same result later.
+///
+/// [`iter_from`]: PagedEndpointExt::iter_from
+/// [`stream_from`]: PagedEndpointExt::stream_from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageCursor {
+    /// Page containing the next result
+    pub page: usize,
+    /// Results per page, if set
+    pub per_page: Option<usize>,
+    /// Number of results on `page` that were already returned
+    pub offset: usize,
+}
+
+impl PageCursor {
+    /// Create a cursor at the first result of `page`
+    pub fn new(page: usize, per_page: Option<usize>) -> Self {
+        Self {
+            page: page.max(1),
+            per_page,
+            offset: 0,
+        }
+    }
+
+    /// The first result of the next page
+    fn next_page(self) -> Self {
+        Self::new(self.page + 1, self.per_page)
+    }
+
+    /// Shrink the page size, so that requesting this page fetches as few
+    /// results beyond the next `remaining` as possible
+    ///
+    /// The smallest page size at least as large as the results still needed
+    /// that keeps the first result of the page at the same position is
+    /// chosen, so the cursor keeps pointing at the same result.
+    fn shrink(self, remaining: Option<usize>) -> Self {
+        let (Some(remaining), Some(per_page)) = (remaining, self.per_page) else {
+            return self;
+        };
+
+        let wanted = self.offset + remaining;
+        if wanted >= per_page {
+            return self;
+        }
+
+        let first = (self.page - 1) * per_page;
+        let size = (wanted.max(1)..per_page)
+            .find(|size| first % size == 0)
+            .unwrap_or(per_page);
+        Self {
+            page: first / size + 1,
+            per_page: Some(size),
+            offset: self.offset,
+        }
+    }
+
+    fn page<'a, E>(&self, paged: &'a E) -> Page<'a, E>
+    where
+        E: Endpoint + Pageable,
+    {
+        Page::builder(paged)
+            .page(self.page)
+            .per_page(self.per_page)
+            .build()
+    }
+
+    /// Skip the results that were already returned, and pair each remaining
+    /// result with the cursor following it
+    fn tag<T>(self, results: Vec<T>) -> impl Iterator<Item = (T, PageCursor)> {
+        let len = results.len();
+        results
+            .into_iter()
+            .enumerate()
+            .skip(self.offset)
+            .map(move |(index, result)| {
+                let cursor = if index + 1 < len {
+                    Self {
+                        offset: index + 1,
+                        ..self
+                    }
                 } else {
-                    Some(pagination.page + 1)
+                    self.next_page()
                 };
-                Ok(Some((
-                    futures::stream::iter(page.inner.into_iter().map(Ok)),
-                    next_state,
-                )))
+                (result, cursor)
+            })
+    }
+}
+
+impl Default for PageCursor {
+    fn default() -> Self {
+        Self::new(1, None)
+    }
+}
+
+/// Pagination state shared by all paged iterators and streams
+///
+/// Pages are requested one after another, until a short or empty page is
+/// returned, a request fails, or the limit on the number of results is
+/// reached.
+#[derive(Debug, Clone, Copy)]
+struct Paginator {
+    next_page: Option<PageCursor>,
+    remaining: Option<usize>,
+    fallback: MissingPagination,
+}
+
+impl Paginator {
+    fn new(cursor: PageCursor, limit: Option<usize>, fallback: MissingPagination) -> Self {
+        Self {
+            next_page: Some(cursor),
+            remaining: limit,
+            fallback,
+        }
+    }
+
+    /// The next page to request, or `None` at the end of the results
+    fn next_page(&self) -> Option<PageCursor> {
+        if self.remaining == Some(0) {
+            return None;
+        }
+        self.next_page.map(|cursor| cursor.shrink(self.remaining))
+    }
+
+    /// Advance past `page`, the response to the request for `cursor`
+    ///
+    /// Results beyond the limit are removed from `page`.
+    fn advance<P, T, E>(
+        &mut self,
+        paged: &P,
+        cursor: PageCursor,
+        page: &mut Collection<T>,
+    ) -> Result<(), ApiError<E>>
+    where
+        P: Endpoint,
+        E: Error + Send + Sync + 'static,
+    {
+        self.next_page = None;
+
+        let last_page = if page.inner.is_empty() {
+            true
+        } else {
+            match (page.pagination, self.fallback) {
+                (Some(pagination), _) => pagination.page_size < pagination.per_page,
+                (None, MissingPagination::UntilEmpty) => false,
+                (None, MissingPagination::Error) => {
+                    return Err(ApiError::missing_pagination(&paged.endpoint(), cursor.page))
+                }
             }
+        };
+
+        if let Some(remaining) = &mut self.remaining {
+            page.inner.truncate(cursor.offset + *remaining);
+            *remaining -= page.inner.len().saturating_sub(cursor.offset);
+        }
+        if !last_page {
+            self.next_page = Some(cursor.next_page());
+        }
+        Ok(())
+    }
+
+    /// Stop after a failed request
+    fn fail(&mut self) {
+        self.next_page = None;
+    }
+}
+
+/// Stream type for the [`stream`] method on the [`PagedEndpointExt`] trait
+///
+/// [`stream`]: PagedEndpointExt::stream
+pub struct PagedStream<'a, T, E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    inner: BoxStream<'a, Result<(T, PageCursor), ApiError<E>>>,
+    cursor: PageCursor,
+}
+
+impl<'a, T, E> PagedStream<'a, T, E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    fn new<P, C>(
+        paged: &'a P,
+        client: &'a C,
+        cursor: PageCursor,
+        limit: Option<usize>,
+        fallback: MissingPagination,
+    ) -> Self
+    where
+        P: Endpoint + Pageable + Send + Sync,
+        T: DeserializeOwned + Send + 'static,
+        C: AsyncClient<Error = E> + Sync,
+    {
+        let paginator = Paginator::new(cursor, limit, fallback);
+        let inner = futures::stream::try_unfold(paginator, move |mut paginator| async move {
+            let Some(cursor) = paginator.next_page() else {
+                return Ok::<_, ApiError<E>>(None);
+            };
+
+            let mut page = cursor.page(paged).query_async(client).await?;
+            paginator.advance(paged, cursor, &mut page)?;
+
+            Ok(Some((
+                futures::stream::iter(cursor.tag(page.inner).map(Ok)),
+                paginator,
+            )))
         })
         .try_flatten()
-        .boxed()
+        .boxed();
+
+        Self::from_inner(inner, cursor)
+    }
+
+    fn from_inner(
+        inner: BoxStream<'a, Result<(T, PageCursor), ApiError<E>>>,
+        cursor: PageCursor,
+    ) -> Self {
+        Self { inner, cursor }
+    }
+
+    /// Position of the next result
+    pub fn cursor(&self) -> PageCursor {
+        self.cursor
+    }
+}
+
+impl<'a, E> PagedStream<'a, serde_json::Value, E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    /// Deserialize each raw result independently
+    fn lenient<T>(self) -> PagedStream<'a, Result<T, ItemError>, E>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        let start = self.cursor;
+        let inner = self
+            .inner
+            .scan(start, |next, result| {
+                let result = result.map(|(value, cursor)| {
+                    // The cursor before a result is its position in the page
+                    let index = std::mem::replace(next, cursor).offset;
+                    (ItemError::deserialize(index, value), cursor)
+                });
+                futures::future::ready(Some(result))
+            })
+            .boxed();
+
+        PagedStream::from_inner(inner, start)
+    }
+}
+
+impl<T, E> Stream for PagedStream<'_, T, E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    type Item = Result<T, ApiError<E>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let item = ready!(self.inner.poll_next_unpin(cx)).map(|result| {
+            result.map(|(item, cursor)| {
+                self.cursor = cursor;
+                item
+            })
+        });
+        Poll::Ready(item)
+    }
+}
+
+impl<T, E> fmt::Debug for PagedStream<'_, T, E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PagedStream")
+            .field("cursor", &self.cursor)
+            .finish_non_exhaustive()
     }
 }
 
@@ -90,25 +562,37 @@ where
 /// [`iter`]: PagedEndpointExt::iter
 #[derive(Debug)]
 pub struct PagedIter<'a, E, C, T> {
+    paged: &'a E,
     client: &'a C,
-    state: Page<'a, E>,
-    last_page: bool,
-    current_page: Vec<T>,
+    paginator: Paginator,
+    cursor: PageCursor,
+    current_page: Vec<(T, PageCursor)>,
 }
 
 impl<'a, E, C, T> PagedIter<'a, E, C, T>
 where
     E: Endpoint + Pageable,
 {
-    pub(crate) fn new(paged: &'a E, client: &'a C) -> Self {
-        let state = Page::builder(paged).page(1).build();
+    pub(crate) fn new(
+        paged: &'a E,
+        client: &'a C,
+        cursor: PageCursor,
+        limit: Option<usize>,
+        fallback: MissingPagination,
+    ) -> Self {
         Self {
+            paged,
             client,
-            state,
-            last_page: false,
+            paginator: Paginator::new(cursor, limit, fallback),
+            cursor,
             current_page: Vec::new(),
         }
     }
+
+    /// Position of the next result
+    pub fn cursor(&self) -> PageCursor {
+        self.cursor
+    }
 }
 
 impl<'a, E, C, T> Iterator for PagedIter<'a, E, C, T>
@@ -120,30 +604,71 @@ where
     type Item = Result<T, ApiError<C::Error>>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current_page.is_empty() {
-            if self.last_page {
-                return None;
-            }
+        while self.current_page.is_empty() {
+            let cursor = self.paginator.next_page()?;
 
-            self.current_page = match self.state.query(self.client) {
-                Ok(page) => {
-                    let pagination = page.pagination.expect("Missing pagination info");
-
-                    if pagination.per_page < pagination.page_size {
-                        self.last_page = true;
-                    }
-
-                    self.state.page = pagination.page + 1;
-
-                    page.inner
+            let mut page = match cursor.page(self.paged).query(self.client) {
+                Ok(page) => page,
+                Err(err) => {
+                    self.paginator.fail();
+                    return Some(Err(err));
                 }
-                Err(err) => return Some(Err(err)),
             };
+            if let Err(err) = self.paginator.advance(self.paged, cursor, &mut page) {
+                return Some(Err(err));
+            }
 
+            self.current_page = cursor.tag(page.inner).collect();
             self.current_page.reverse();
         }
 
-        self.current_page.pop().map(Ok)
+        self.current_page.pop().map(|(item, cursor)| {
+            self.cursor = cursor;
+            Ok(item)
+        })
+    }
+}
+
+/// Iterator type for the [`iter_lenient`] method on the [`PagedEndpointExt`]
+/// trait
+///
+/// [`iter_lenient`]: PagedEndpointExt::iter_lenient
+#[derive(Debug)]
+pub struct LenientIter<'a, E, C, T> {
+    inner: PagedIter<'a, E, C, serde_json::Value>,
+    _results: PhantomData<T>,
+}
+
+impl<'a, E, C, T> LenientIter<'a, E, C, T>
+where
+    E: Endpoint + Pageable,
+{
+    fn new(inner: PagedIter<'a, E, C, serde_json::Value>) -> Self {
+        Self {
+            inner,
+            _results: PhantomData,
+        }
+    }
+
+    /// Position of the next result
+    pub fn cursor(&self) -> PageCursor {
+        self.inner.cursor()
+    }
+}
+
+impl<E, C, T> Iterator for LenientIter<'_, E, C, T>
+where
+    E: Endpoint + Pageable,
+    C: Client,
+    T: DeserializeOwned,
+{
+    type Item = Result<Result<T, ItemError>, ApiError<C::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // The cursor before a result is its position in the page
+        let index = self.inner.cursor().offset;
+        let value = self.inner.next()?;
+        Some(value.map(|value| ItemError::deserialize(index, value)))
     }
 }
 
@@ -153,6 +678,8 @@ pub struct PageBuilder<'a, E> {
     inner: &'a E,
     page: Option<usize>,
     per_page: Option<usize>,
+    limit: Option<usize>,
+    missing_pagination: MissingPagination,
 }
 
 impl<'a, E> PageBuilder<'a, E>
@@ -165,6 +692,8 @@ where
             inner: paged,
             page: None,
             per_page: None,
+            limit: None,
+            missing_pagination: MissingPagination::default(),
         }
     }
 
@@ -186,6 +715,26 @@ where
         self
     }
 
+    /// Maximum number of results returned by the iterators and streams
+    /// created from this builder. Example: `100`
+    ///
+    /// When [`per_page`](Self::per_page) is set, the last request is shrunk
+    /// to fetch no more results than needed.
+    pub fn limit<T>(mut self, value: T) -> Self
+    where
+        T: Into<Option<usize>>,
+    {
+        self.limit = value.into();
+        self
+    }
+
+    /// What the iterators and streams created from this builder do when a
+    /// page has no pagination metadata. Example: `MissingPagination::Error`
+    pub fn missing_pagination(mut self, value: MissingPagination) -> Self {
+        self.missing_pagination = value;
+        self
+    }
+
     /// Returns a [`Page`] that can be queried for a set of elements.
     pub fn build(self) -> Page<'a, E>
     where
@@ -197,6 +746,154 @@ where
             per_page: self.per_page,
         }
     }
+
+    /// Create an Iterator over the results, starting at this page
+    pub fn iter<T, C>(self, client: &'a C) -> PagedIter<'a, E, C, T>
+    where
+        C: Client,
+        T: DeserializeOwned,
+    {
+        PagedIter::new(
+            self.inner,
+            client,
+            self.cursor(),
+            self.limit,
+            self.missing_pagination,
+        )
+    }
+
+    /// Create an async Stream over the results, starting at this page
+    pub fn stream<T, C>(self, client: &'a C) -> PagedStream<'a, T, C::Error>
+    where
+        T: DeserializeOwned + Send + 'static,
+        C: AsyncClient + Sync,
+        E: Send + Sync,
+    {
+        PagedStream::new(
+            self.inner,
+            client,
+            self.cursor(),
+            self.limit,
+            self.missing_pagination,
+        )
+    }
+
+    /// Create an Iterator over the results, starting at this page,
+    /// deserializing each result independently
+    ///
+    /// See [`PagedEndpointExt::iter_lenient`].
+    pub fn iter_lenient<T, C>(self, client: &'a C) -> LenientIter<'a, E, C, T>
+    where
+        C: Client,
+        T: DeserializeOwned,
+    {
+        LenientIter::new(self.iter(client))
+    }
+
+    /// Create an async Stream over the results, starting at this page,
+    /// deserializing each result independently
+    ///
+    /// See [`PagedEndpointExt::stream_lenient`].
+    pub fn stream_lenient<T, C>(
+        self,
+        client: &'a C,
+    ) -> PagedStream<'a, Result<T, ItemError>, C::Error>
+    where
+        T: DeserializeOwned + Send + 'static,
+        C: AsyncClient + Sync,
+        E: Send + Sync,
+    {
+        let values: PagedStream<'a, serde_json::Value, C::Error> = self.stream(client);
+        values.lenient()
+    }
+
+    /// Create an Iterator over whole pages, starting at this page
+    pub fn pages<T, C>(self, client: &'a C) -> PagesIter<'a, E, C, T>
+    where
+        C: Client,
+        T: DeserializeOwned,
+    {
+        PagesIter {
+            paged: self.inner,
+            client,
+            paginator: self.paginator(),
+            _results: PhantomData,
+        }
+    }
+
+    /// Create an async Stream over whole pages, starting at this page
+    pub fn pages_stream<T, C>(
+        self,
+        client: &'a C,
+    ) -> BoxStream<'a, Result<Collection<T>, ApiError<C::Error>>>
+    where
+        T: DeserializeOwned + Send + 'static,
+        C: AsyncClient + Sync,
+        E: Send + Sync,
+    {
+        let paged = self.inner;
+        futures::stream::try_unfold(self.paginator(), move |mut paginator| async move {
+            let Some(cursor) = paginator.next_page() else {
+                return Ok::<_, ApiError<C::Error>>(None);
+            };
+
+            let mut page: Collection<T> = cursor.page(paged).query_async(client).await?;
+            paginator.advance(paged, cursor, &mut page)?;
+
+            Ok(Some((page, paginator)))
+        })
+        .try_filter(|page| futures::future::ready(!page.inner.is_empty()))
+        .boxed()
+    }
+
+    fn cursor(&self) -> PageCursor {
+        PageCursor::new(self.page.unwrap_or(1), self.per_page)
+    }
+
+    fn paginator(&self) -> Paginator {
+        Paginator::new(self.cursor(), self.limit, self.missing_pagination)
+    }
+}
+
+/// Iterator type for the [`pages`] method on the [`PagedEndpointExt`] trait
+///
+/// [`pages`]: PagedEndpointExt::pages
+#[derive(Debug)]
+pub struct PagesIter<'a, E, C, T> {
+    paged: &'a E,
+    client: &'a C,
+    paginator: Paginator,
+    _results: PhantomData<T>,
+}
+
+impl<'a, E, C, T> Iterator for PagesIter<'a, E, C, T>
+where
+    E: Endpoint + Pageable,
+    C: Client,
+    T: DeserializeOwned,
+{
+    type Item = Result<Collection<T>, ApiError<C::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let cursor = self.paginator.next_page()?;
+
+            let mut page = match cursor.page(self.paged).query(self.client) {
+                Ok(page) => page,
+                Err(err) => {
+                    self.paginator.fail();
+                    return Some(Err(err));
+                }
+            };
+            if let Err(err) = self.paginator.advance(self.paged, cursor, &mut page) {
+                return Some(Err(err));
+            }
+
+            if !page.inner.is_empty() {
+                return Some(Ok(page));
+            }
+        }
+    }
 }
 
 /// Represents a single page of results
@@ -226,15 +923,12 @@ where
         params.apply_to(&mut url);
         Ok(url)
     }
-}
 
-impl<'a, E, T, C> Query<Collection<T>, C> for Page<'a, E>
-where
-    E: Endpoint + Pageable,
-    T: DeserializeOwned,
-    C: Client,
-{
-    fn query(&self, client: &C) -> Result<Collection<T>, ApiError<<C>::Error>> {
+    /// The request for this page, with its body and URL
+    fn request<C: RestClient>(
+        &self,
+        client: &C,
+    ) -> Result<(RequestBuilder, Vec<u8>, http::Uri), ApiError<C::Error>> {
         let url = self.page_url(client)?;
         let url = url_to_http_uri(&url);
 
@@ -251,9 +945,54 @@ where
             (request, Vec::new())
         };
 
+        Ok((request, body, url))
+    }
+
+    fn query_page<R, C>(&self, client: &C) -> Result<R, ApiError<C::Error>>
+    where
+        R: DeserializeOwned,
+        C: Client,
+    {
+        let (request, body, url) = self.request(client)?;
         let response = client.rest(request, body)?;
 
-        deserialize_response::<_, C>(response).map_err(|e| ApiError::from_http_response(e, url))
+        deserialize_response(response)
+            .map_err(|e| ApiError::from_http_response(e, &self.inner.endpoint(), url))
+    }
+
+    async fn query_page_async<R, C>(&self, client: &C) -> Result<R, ApiError<C::Error>>
+    where
+        R: DeserializeOwned,
+        C: AsyncClient + Sync,
+        E: Sync,
+    {
+        let (request, body, url) = self.request(client)?;
+        let response = client.rest_async(request, body).await?;
+
+        deserialize_response(response)
+            .map_err(|e| ApiError::from_http_response(e, &self.inner.endpoint(), url))
+    }
+}
+
+impl<'a, E, T, C> Query<Collection<T>, C> for Page<'a, E>
+where
+    E: Endpoint + Pageable,
+    T: DeserializeOwned,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<Collection<T>, ApiError<<C>::Error>> {
+        self.query_page(client)
+    }
+}
+
+impl<'a, E, T, C> Query<LenientCollection<T>, C> for Page<'a, E>
+where
+    E: Endpoint + Pageable,
+    T: DeserializeOwned,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<LenientCollection<T>, ApiError<<C>::Error>> {
+        self.query_page(client)
     }
 }
 
@@ -265,28 +1004,588 @@ where
     E: Endpoint + Pageable + Sync,
 {
     async fn query_async(&self, client: &C) -> Result<Collection<T>, ApiError<C::Error>> {
-        let url = self.page_url(client)?;
-        let url = url_to_http_uri(&url);
+        self.query_page_async(client).await
+    }
+}
 
-        let body = self.inner.body()?;
+#[async_trait]
+impl<'a, T, C, E> AsyncQuery<LenientCollection<T>, C> for Page<'a, E>
+where
+    T: DeserializeOwned + Send + 'static,
+    C: AsyncClient + Sync,
+    E: Endpoint + Pageable + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<LenientCollection<T>, ApiError<C::Error>> {
+        self.query_page_async(client).await
+    }
+}
 
-        let request = Request::builder()
-            .method(self.inner.method())
-            .uri(url.clone());
+fn is_first_page(value: &usize) -> bool {
+    *value <= 1
+}
 
-        let (request, body) = if let Some((mime, data)) = body {
-            let request = request.header(header::CONTENT_TYPE, mime);
-            (request, data)
-        } else {
-            (request, Vec::new())
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use bytes::Bytes;
+    use http::{request::Builder as RequestBuilder, Response};
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::{
+        api::events::ListEvents,
+        mock::{Expectation, MockClient},
+    };
+
+    fn events_page(page: usize, names: &[&str]) -> Value {
+        let events: Vec<_> = names.iter().map(|name| json!({ "name": name })).collect();
+        json!({
+            "events": events,
+            "page": page,
+            "perPage": 2,
+            "pageSize": names.len(),
+        })
+    }
+
+    /// Answers requests for the second page last, so that concurrent pages
+    /// complete out of order
+    struct SlowSecondPage(MockClient);
+
+    impl RestClient for SlowSecondPage {
+        type Error = <MockClient as RestClient>::Error;
+
+        fn rest_endpoint(&self, endpoint: &str) -> Result<url::Url, ApiError<Self::Error>> {
+            self.0.rest_endpoint(endpoint)
+        }
+    }
+
+    #[async_trait]
+    impl AsyncClient for SlowSecondPage {
+        async fn rest_async(
+            &self,
+            request: RequestBuilder,
+            body: Vec<u8>,
+        ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+            if request.uri_ref().and_then(|uri| uri.query()) == Some("page=2") {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }
+            self.0.rest_async(request, body).await
+        }
+    }
+
+    #[tokio::test]
+    async fn stream_concurrent_in_page_order() {
+        let client = MockClient::new();
+        client
+            .expect(
+                Expectation::get("/events")
+                    .query("")
+                    .json(&events_page(1, &["a", "b"])),
+            )
+            .expect(
+                Expectation::get("/events")
+                    .query("page=2")
+                    .json(&events_page(2, &["c", "d"])),
+            )
+            .expect(
+                Expectation::get("/events")
+                    .query("page=3")
+                    .json(&events_page(3, &["e"])),
+            )
+            .expect(
+                Expectation::get("/events")
+                    .query("page=4")
+                    .json(&events_page(4, &[])),
+            );
+
+        let endpoint = ListEvents::builder().build().unwrap();
+        let client = SlowSecondPage(client);
+        let events: Vec<Value> = endpoint
+            .stream_concurrent(&client, 3)
+            .try_collect()
+            .await
+            .unwrap();
+
+        let names: Vec<_> = events.iter().map(|event| event["name"].clone()).collect();
+        assert_eq!(names, ["a", "b", "c", "d", "e"]);
+
+        // The mock logs requests as it answers them. The first three pages are
+        // requested together and the fourth once the first is yielded. The slow
+        // second page is answered last, and the short third page ends the
+        // stream before a fifth page is requested.
+        client.0.assert_requests(&[
+            "/events",
+            "/events?page=3",
+            "/events?page=4",
+            "/events?page=2",
+        ]);
+    }
+
+    #[tokio::test]
+    async fn stream_concurrent_stops_on_error() {
+        let client = MockClient::new();
+        client
+            .expect(
+                Expectation::get("/events")
+                    .query("")
+                    .json(&events_page(1, &["a", "b"])),
+            )
+            .expect(Expectation::get("/events").query("page=2").status(500))
+            .expect(Expectation::get("/events").json(&events_page(3, &["c", "d"])));
+
+        let endpoint = ListEvents::builder().build().unwrap();
+        let results: Vec<Result<Value, _>> = endpoint.stream_concurrent(&client, 2).collect().await;
+
+        assert_eq!(results.len(), 3);
+        assert!(results[..2].iter().all(Result::is_ok));
+        assert!(results[2].is_err());
+    }
+
+    fn resumable_client() -> MockClient {
+        let client = MockClient::new();
+        client
+            .expect(
+                Expectation::get("/events")
+                    .query("")
+                    .json(&events_page(1, &["a", "b"])),
+            )
+            .expect(
+                Expectation::get("/events")
+                    .query("page=2")
+                    .json(&events_page(2, &["c", "d"])),
+            )
+            .expect(Expectation::get("/events").json(&events_page(3, &[])));
+        client
+    }
+
+    #[test]
+    fn iter_resumes_from_cursor() {
+        let client = resumable_client();
+        let endpoint = ListEvents::builder().build().unwrap();
+
+        let mut iter = endpoint.iter::<Value, _>(&client);
+        assert_eq!(iter.cursor(), PageCursor::default());
+        iter.next().unwrap().unwrap();
+        iter.next().unwrap().unwrap();
+        iter.next().unwrap().unwrap();
+
+        let cursor = iter.cursor();
+        assert_eq!(
+            cursor,
+            PageCursor {
+                page: 2,
+                per_page: None,
+                offset: 1
+            }
+        );
+
+        let saved = serde_json::to_string(&cursor).unwrap();
+        let cursor: PageCursor = serde_json::from_str(&saved).unwrap();
+        let rest: Vec<Value> = endpoint
+            .iter_from(&client, cursor)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(rest, [json!({"name": "d"})]);
+    }
+
+    #[tokio::test]
+    async fn stream_resumes_from_cursor() {
+        let client = resumable_client();
+        let endpoint = ListEvents::builder().build().unwrap();
+
+        let mut stream = endpoint.stream::<Value, _>(&client);
+        stream.next().await.unwrap().unwrap();
+        stream.next().await.unwrap().unwrap();
+        assert_eq!(stream.cursor(), PageCursor::new(2, None));
+
+        let rest: Vec<Value> = endpoint
+            .stream_from(&client, stream.cursor())
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(rest, [json!({"name": "c"}), json!({"name": "d"})]);
+    }
+
+    #[test]
+    fn pages_with_per_page() {
+        let client = MockClient::new();
+        client
+            .expect(
+                Expectation::get("/events")
+                    .query("page=2&perPage=2")
+                    .json(&events_page(2, &["c", "d"])),
+            )
+            .expect(
+                Expectation::get("/events")
+                    .query("page=3&perPage=2")
+                    .json(&events_page(3, &["e"])),
+            );
+
+        let endpoint = ListEvents::builder().build().unwrap();
+        let pages: Vec<Collection<Value>> = endpoint
+            .page()
+            .page(2)
+            .per_page(2)
+            .pages(&client)
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        let sizes: Vec<_> = pages
+            .iter()
+            .map(|page| page.pagination.unwrap().page_size)
+            .collect();
+        assert_eq!(sizes, [2, 1]);
+        client.assert_requests(&["/events?page=2&perPage=2", "/events?page=3&perPage=2"]);
+    }
+
+    #[tokio::test]
+    async fn pages_stream_stops_on_empty_page() {
+        let client = resumable_client();
+        let endpoint = ListEvents::builder().build().unwrap();
+
+        let pages: Vec<Collection<Value>> =
+            endpoint.pages_stream(&client).try_collect().await.unwrap();
+
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[1].pagination.unwrap().page, 2);
+        client.assert_requests(&["/events", "/events?page=2", "/events?page=3"]);
+    }
+
+    fn sized_events_page(page: usize, per_page: usize, names: &[&str]) -> Value {
+        let mut value = events_page(page, names);
+        value["perPage"] = per_page.into();
+        value
+    }
+
+    #[test]
+    fn iter_limit_shrinks_last_request() {
+        let client = MockClient::new();
+        client
+            .expect(
+                Expectation::get("/events")
+                    .query("page=2&perPage=4")
+                    .json(&sized_events_page(2, 4, &["e", "f", "g", "h"])),
+            )
+            .expect(
+                Expectation::get("/events")
+                    .query("page=9&perPage=1")
+                    .json(&sized_events_page(9, 1, &["i"])),
+            );
+
+        let endpoint = ListEvents::builder().build().unwrap();
+        let events: Vec<Value> = endpoint
+            .page()
+            .page(2)
+            .per_page(4)
+            .limit(5)
+            .iter(&client)
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(events.len(), 5);
+        assert_eq!(events[4], json!({"name": "i"}));
+        client.assert_requests(&["/events?page=2&perPage=4", "/events?page=9&perPage=1"]);
+    }
+
+    #[tokio::test]
+    async fn stream_limit_shrinks_last_request() {
+        let client = MockClient::new();
+        client
+            .expect(
+                Expectation::get("/events")
+                    .query("perPage=4")
+                    .json(&sized_events_page(1, 4, &["a", "b", "c", "d"])),
+            )
+            .expect(
+                Expectation::get("/events")
+                    .query("page=3&perPage=2")
+                    .json(&sized_events_page(3, 2, &["e", "f"])),
+            )
+            .expect(
+                Expectation::get("/events")
+                    .query("perPage=3")
+                    .json(&sized_events_page(1, 3, &["a", "b", "c"])),
+            );
+
+        let endpoint = ListEvents::builder().build().unwrap();
+        let events: Vec<Value> = endpoint
+            .page()
+            .per_page(4)
+            .limit(6)
+            .stream(&client)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(events.len(), 6);
+
+        let pages: Vec<Collection<Value>> = endpoint
+            .page()
+            .per_page(4)
+            .limit(3)
+            .pages_stream(&client)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(pages.len(), 1);
+        assert_eq!(pages[0].inner.len(), 3);
+
+        client.assert_requests(&[
+            "/events?perPage=4",
+            "/events?page=3&perPage=2",
+            "/events?perPage=3",
+        ]);
+    }
+
+    #[test]
+    fn shrink_keeps_position() {
+        let cursor = PageCursor::new(3, Some(10));
+        assert_eq!(cursor.shrink(None), cursor);
+        assert_eq!(cursor.shrink(Some(10)), cursor);
+        assert_eq!(cursor.shrink(Some(5)), PageCursor::new(5, Some(5)));
+        assert_eq!(cursor.shrink(Some(3)), PageCursor::new(6, Some(4)));
+        assert_eq!(cursor.shrink(Some(1)), PageCursor::new(21, Some(1)));
+
+        let cursor = PageCursor {
+            offset: 2,
+            ..PageCursor::new(1, Some(10))
         };
+        assert_eq!(
+            cursor.shrink(Some(1)),
+            PageCursor {
+                offset: 2,
+                ..PageCursor::new(1, Some(3))
+            }
+        );
+    }
 
-        let response = client.rest_async(request, body).await?;
+    #[test]
+    fn missing_pagination_until_empty() {
+        let client = MockClient::new();
+        client
+            .expect(
+                Expectation::get("/events")
+                    .query("")
+                    .json(&json!({"events": [{"name": "a"}]})),
+            )
+            .expect(Expectation::get("/events").json(&json!({"events": []})));
 
-        deserialize_response::<_, C>(response).map_err(|e| ApiError::from_http_response(e, url))
+        let endpoint = ListEvents::builder().build().unwrap();
+        let events: Vec<Value> = endpoint.iter(&client).collect::<Result<_, _>>().unwrap();
+
+        assert_eq!(events, [json!({"name": "a"})]);
+        client.assert_requests(&["/events", "/events?page=2"]);
     }
-}
 
-fn is_first_page(value: &usize) -> bool {
-    *value <= 1
+    #[tokio::test]
+    async fn missing_pagination_error() {
+        let client = MockClient::new();
+        client.expect(Expectation::get("/events").json(&json!({"events": [{"name": "a"}]})));
+
+        let endpoint = ListEvents::builder().build().unwrap();
+        let results: Vec<Result<Value, _>> = endpoint
+            .page()
+            .missing_pagination(MissingPagination::Error)
+            .stream(&client)
+            .collect()
+            .await;
+
+        assert_eq!(results.len(), 1);
+        assert!(matches!(
+            &results[0],
+            Err(ApiError::MissingPagination { endpoint, page: 1 }) if endpoint == "/events"
+        ));
+    }
+
+    /// Serve `pages` of events with 2 results per page, followed by empty
+    /// pages
+    fn paged_client(pages: &[&[&str]]) -> MockClient {
+        let client = MockClient::new();
+        for (index, names) in pages.iter().enumerate() {
+            let query = match index {
+                0 => String::new(),
+                index => format!("page={}", index + 1),
+            };
+            client.expect(
+                Expectation::get("/events")
+                    .query(query)
+                    .json(&events_page(index + 1, names)),
+            );
+        }
+        client.expect(Expectation::get("/events").json(&events_page(pages.len() + 1, &[])));
+        client
+    }
+
+    /// The requests received by `client`. Unlike
+    /// [`MockClient::assert_requests`], this does not require the trailing
+    /// empty page of [`paged_client`] to be requested.
+    fn request_log(client: &MockClient) -> Vec<String> {
+        client.requests().iter().map(ToString::to_string).collect()
+    }
+
+    /// Crawl `pages` with every adapter, checking that they all return the
+    /// same results after the same requests
+    async fn crawl(pages: &[&[&str]]) -> (Vec<Value>, Vec<String>) {
+        let endpoint = ListEvents::builder().build().unwrap();
+
+        let client = paged_client(pages);
+        let iter: Vec<Value> = endpoint.iter(&client).collect::<Result<_, _>>().unwrap();
+        let requests = request_log(&client);
+
+        let client = paged_client(pages);
+        let stream: Vec<Value> = endpoint.stream(&client).try_collect().await.unwrap();
+        assert_eq!(stream, iter);
+        assert_eq!(request_log(&client), requests);
+
+        let client = paged_client(pages);
+        let by_page: Vec<Collection<Value>> =
+            endpoint.pages(&client).collect::<Result<_, _>>().unwrap();
+        assert_eq!(
+            by_page
+                .into_iter()
+                .flat_map(Collection::into_inner)
+                .collect::<Vec<_>>(),
+            iter
+        );
+        assert_eq!(request_log(&client), requests);
+
+        let client = paged_client(pages);
+        let by_page: Vec<Collection<Value>> =
+            endpoint.pages_stream(&client).try_collect().await.unwrap();
+        assert_eq!(
+            by_page
+                .into_iter()
+                .flat_map(Collection::into_inner)
+                .collect::<Vec<_>>(),
+            iter
+        );
+        assert_eq!(request_log(&client), requests);
+
+        let client = paged_client(pages);
+        let concurrent: Vec<Value> = endpoint
+            .stream_concurrent(&client, 4)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(concurrent, iter);
+
+        (iter, requests)
+    }
+
+    #[tokio::test]
+    async fn exact_multiple_of_page_size() {
+        let (results, requests) = crawl(&[&["a", "b"], &["c", "d"]]).await;
+
+        assert_eq!(results.len(), 4);
+        assert_eq!(requests, ["/events", "/events?page=2", "/events?page=3"]);
+    }
+
+    #[tokio::test]
+    async fn short_final_page() {
+        let (results, requests) = crawl(&[&["a", "b"], &["c"]]).await;
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(requests, ["/events", "/events?page=2"]);
+    }
+
+    #[tokio::test]
+    async fn empty_first_page() {
+        let (results, requests) = crawl(&[]).await;
+
+        assert!(results.is_empty());
+        assert_eq!(requests, ["/events"]);
+    }
+
+    fn lenient_client() -> MockClient {
+        let client = MockClient::new();
+        client
+            .expect(Expectation::get("/events").query("").json(&json!({
+                "events": [{"name": "a"}, {"name": 1}],
+                "page": 1,
+                "perPage": 2,
+                "pageSize": 2,
+            })))
+            .expect(
+                Expectation::get("/events")
+                    .query("page=2")
+                    .json(&events_page(2, &["c"])),
+            );
+        client
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Named {
+        name: String,
+    }
+
+    fn check_lenient(results: Vec<Result<Named, ItemError>>) {
+        let names: Vec<_> = results
+            .iter()
+            .map(|result| result.as_ref().ok().map(|named| named.name.as_str()))
+            .collect();
+        assert_eq!(names, [Some("a"), None, Some("c")]);
+
+        let err = results[1].as_ref().unwrap_err();
+        assert_eq!((err.index, err.path.as_str()), (1, "[1].name"));
+        assert_eq!(err.value, json!({"name": 1}));
+    }
+
+    #[test]
+    fn iter_lenient_keeps_invalid_results() {
+        let client = lenient_client();
+        let endpoint = ListEvents::builder().build().unwrap();
+
+        let results = endpoint
+            .iter_lenient(&client)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        check_lenient(results);
+    }
+
+    #[tokio::test]
+    async fn stream_lenient_keeps_invalid_results() {
+        let client = lenient_client();
+        let endpoint = ListEvents::builder().build().unwrap();
+
+        let results = endpoint
+            .stream_lenient(&client)
+            .try_collect()
+            .await
+            .unwrap();
+        check_lenient(results);
+    }
+
+    #[test]
+    fn lenient_page() {
+        let client = lenient_client();
+        let endpoint = ListEvents::builder().build().unwrap();
+
+        let page: LenientCollection<Named> = endpoint.page().build().query(&client).unwrap();
+        assert_eq!(page.inner, [Named { name: "a".into() }]);
+        assert_eq!(page.errors[0].path, "[1].name");
+        assert_eq!(page.pagination.unwrap().page, 1);
+    }
+
+    #[tokio::test]
+    async fn lenient_from_page() {
+        let client = lenient_client();
+        let endpoint = ListEvents::builder().build().unwrap();
+
+        let results: Vec<Result<Named, ItemError>> = endpoint
+            .page()
+            .page(2)
+            .iter_lenient(&client)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].as_ref().unwrap().name, "c");
+
+        let results = endpoint
+            .page()
+            .stream_lenient(&client)
+            .try_collect()
+            .await
+            .unwrap();
+        check_lenient(results);
+    }
 }
@@ -20,3 +20,97 @@ where
     /// Perform an asynchronous query against the client
     async fn query_async(&self, client: &C) -> Result<T, ApiError<C::Error>>;
 }
+
+/// Query made to a client, for a resource that may not exist
+pub trait OptionalQuery<T, C>
+where
+    C: Client,
+{
+    /// Perform a query against the client, returning `None` if the resource
+    /// is not found
+    fn query_optional(&self, client: &C) -> Result<Option<T>, ApiError<C::Error>>;
+}
+
+impl<Q, T, C> OptionalQuery<T, C> for Q
+where
+    Q: Query<T, C>,
+    C: Client,
+{
+    fn query_optional(&self, client: &C) -> Result<Option<T>, ApiError<C::Error>> {
+        match self.query(client) {
+            Err(err) if err.is_not_found() => Ok(None),
+            result => result.map(Some),
+        }
+    }
+}
+
+/// Asynchronous query made to a client, for a resource that may not exist
+#[async_trait]
+pub trait AsyncOptionalQuery<T, C>
+where
+    C: AsyncClient,
+{
+    /// Perform an asynchronous query against the client, returning `None` if
+    /// the resource is not found
+    async fn query_optional_async(&self, client: &C) -> Result<Option<T>, ApiError<C::Error>>;
+}
+
+#[async_trait]
+impl<Q, T, C> AsyncOptionalQuery<T, C> for Q
+where
+    Q: AsyncQuery<T, C> + Sync,
+    T: 'static,
+    C: AsyncClient + Sync,
+{
+    async fn query_optional_async(&self, client: &C) -> Result<Option<T>, ApiError<C::Error>> {
+        match self.query_async(client).await {
+            Err(err) if err.is_not_found() => Ok(None),
+            result => result.map(Some),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::{
+        api::players::Player,
+        mock::{Expectation, MockClient},
+    };
+
+    fn client() -> MockClient {
+        let client = MockClient::new();
+        client.expect(Expectation::get("/players/known").json(&json!({"tag": "Kro"})));
+        client.expect(
+            Expectation::get("/players/unknown")
+                .status(404)
+                .json(&json!({"message": "player not found"})),
+        );
+        client.expect(Expectation::get("/players/broken").status(500));
+        client
+    }
+
+    #[test]
+    fn query_optional() {
+        let client = client();
+        let query = |id| {
+            let endpoint = Player::builder().id(id).build().unwrap();
+            OptionalQuery::<Value, _>::query_optional(&endpoint, &client)
+        };
+
+        assert_eq!(query("known").unwrap(), Some(json!({"tag": "Kro"})));
+        assert_eq!(query("unknown").unwrap(), None);
+        assert!(query("broken").is_err());
+    }
+
+    #[tokio::test]
+    async fn query_optional_async() {
+        let client = client();
+        let endpoint = Player::builder().id("unknown").build().unwrap();
+        let player: Option<Value> = endpoint.query_optional_async(&client).await.unwrap();
+
+        assert_eq!(player, None);
+    }
+}
@@ -1,6 +1,10 @@
 use std::borrow::Borrow;
 
-use serde::Serialize;
+use serde::{
+    ser::{self, Impossible},
+    Serialize,
+};
+use serde_json::Value;
 use url::Url;
 
 use super::error::BodyError;
@@ -18,7 +22,6 @@ impl QueryParams<'_> {
         self
     }
 
-    #[allow(dead_code)]
     pub(crate) fn append_pair(
         &mut self,
         key: impl AsRef<str>,
@@ -40,11 +43,40 @@ impl QueryParams<'_> {
         self
     }
 
+    /// Append the fields of `value` as query parameters
+    ///
+    /// Fields are appended in the order they are serialized. Missing values are
+    /// skipped, and sequences are appended as a repeated key, so
+    /// `stat: vec!["goals", "saves"]` becomes `stat=goals&stat=saves`.
     pub(crate) fn extend_from(&mut self, value: &impl Serialize) -> Result<&mut Self, BodyError> {
-        value.serialize(self.serializer())?;
+        let mut fields = Fields::default();
+        value.serialize(&mut fields)?;
+
+        for (key, value) in fields.0 {
+            match value {
+                Value::Array(values) => {
+                    for value in values {
+                        self.append_value(key, value)?;
+                    }
+                }
+                value => {
+                    self.append_value(key, value)?;
+                }
+            }
+        }
         Ok(self)
     }
 
+    fn append_value(&mut self, key: &str, value: Value) -> Result<&mut Self, BodyError> {
+        match value {
+            Value::Null => Ok(self),
+            Value::Bool(value) => Ok(self.append_pair(key, value.to_string())),
+            Value::Number(value) => Ok(self.append_pair(key, value.to_string())),
+            Value::String(value) => Ok(self.append_pair(key, value)),
+            value => Err(BodyError::Unsupported(format!("{}={}", key, value))),
+        }
+    }
+
     pub(crate) fn with(value: &impl Serialize) -> Result<Self, BodyError> {
         let mut out = Self::new();
         out.extend_from(value)?;
@@ -63,9 +95,153 @@ impl QueryParams<'_> {
     }
 }
 
-impl<'a> QueryParams<'a> {
-    pub(crate) fn serializer<'b>(&'b mut self) -> serde_urlencoded::Serializer<'a, 'b, String> {
-        serde_urlencoded::Serializer::new(&mut self.0)
+/// Collects the fields of a struct, in the order they are serialized
+#[derive(Default)]
+struct Fields(Vec<(&'static str, Value)>);
+
+fn not_a_struct() -> serde_json::Error {
+    ser::Error::custom("query parameters must be serialized from a struct")
+}
+
+macro_rules! not_a_struct {
+    ($($method:ident($($arg:ty),*)),* $(,)?) => {
+        $(
+            fn $method(self, $(_: $arg),*) -> Result<Self::Ok, Self::Error> {
+                Err(not_a_struct())
+            }
+        )*
+    };
+}
+
+impl ser::Serializer for &mut Fields {
+    type Ok = ();
+    type Error = serde_json::Error;
+    type SerializeSeq = Impossible<(), Self::Error>;
+    type SerializeTuple = Impossible<(), Self::Error>;
+    type SerializeTupleStruct = Impossible<(), Self::Error>;
+    type SerializeTupleVariant = Impossible<(), Self::Error>;
+    type SerializeMap = Impossible<(), Self::Error>;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Impossible<(), Self::Error>;
+
+    not_a_struct!(
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+        serialize_unit_variant(&'static str, u32, &'static str),
+    );
+
+    fn serialize_none(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<(), Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<(), Self::Error> {
+        Err(not_a_struct())
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(not_a_struct())
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Err(not_a_struct())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(not_a_struct())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(not_a_struct())
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Err(not_a_struct())
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(not_a_struct())
+    }
+}
+
+impl ser::SerializeStruct for &mut Fields {
+    type Ok = ();
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        // Only the individual field values go through `serde_json`, so the order
+        // of the fields never depends on its map representation.
+        self.0.push((key, serde_json::to_value(value)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<(), Self::Error> {
+        Ok(())
     }
 }
 
@@ -134,4 +310,78 @@ mod test {
 
         assert_eq!(params.finish(), String::from("some=value&foo=abcdef&bar=7"))
     }
+
+    #[test]
+    fn extend_from_sequences() {
+        #[derive(Debug, Serialize)]
+        struct A {
+            foo: Vec<&'static str>,
+            bar: Option<usize>,
+            baz: Option<bool>,
+        }
+
+        let mut params = QueryParams::new();
+
+        let a = A {
+            foo: vec!["a", "b c"],
+            bar: None,
+            baz: Some(true),
+        };
+
+        params.extend_from(&a).unwrap();
+
+        assert_eq!(params.finish(), String::from("foo=a&foo=b+c&baz=true"))
+    }
+
+    #[test]
+    fn extend_from_nested() {
+        #[derive(Debug, Serialize)]
+        struct A {
+            foo: Vec<Vec<usize>>,
+        }
+
+        let mut params = QueryParams::new();
+
+        let a = A { foo: vec![vec![1]] };
+
+        assert!(matches!(
+            params.extend_from(&a),
+            Err(BodyError::Unsupported(_))
+        ));
+    }
+
+    #[test]
+    fn extend_from_keeps_field_order() {
+        #[derive(Debug, Serialize)]
+        struct A {
+            zeta: usize,
+            beta: Vec<usize>,
+            alpha: usize,
+        }
+
+        let mut params = QueryParams::new();
+
+        let a = A {
+            zeta: 1,
+            beta: vec![2, 3],
+            alpha: 4,
+        };
+
+        params.extend_from(&a).unwrap();
+
+        assert_eq!(
+            params.finish(),
+            String::from("zeta=1&beta=2&beta=3&alpha=4")
+        )
+    }
+
+    #[test]
+    fn extend_from_not_a_struct() {
+        let mut params = QueryParams::new();
+
+        assert!(matches!(
+            params.extend_from(&vec![1]),
+            Err(BodyError::Serialize(_))
+        ));
+    }
 }
@@ -1,5 +1,5 @@
 //! # Records
-//! 
+//!
 //! Endpoints for records
 mod games;
 mod players;
@@ -0,0 +1,384 @@
+use std::{borrow::Cow, fmt::Display};
+
+use serde::{Serialize, Serializer};
+
+/// A stat name accepted by the stats and records endpoints
+///
+/// Known stats are grouped like the fields of [`types::PlayerStats`], and
+/// serialize to the same camelCase names. Any other name can be passed with
+/// [`Stat::Custom`].
+///
+/// ```rust
+/// use octanezsr_api::api::{CoreStat, Stat};
+///
+/// assert_eq!(Stat::from("shootingPercentage"), Stat::Core(CoreStat::ShootingPercentage));
+/// assert_eq!(Stat::from("notAStat"), Stat::Custom("notAStat".into()));
+/// ```
+///
+/// [`types::PlayerStats`]: crate::types::PlayerStats
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Stat<'a> {
+    /// Core stats
+    Core(CoreStat),
+    /// Boost stats
+    Boost(BoostStat),
+    /// Ball stats
+    Ball(BallStat),
+    /// Movement stats
+    Movement(MovementStat),
+    /// Positioning stats
+    Positioning(PositioningStat),
+    /// Demolition stats
+    Demo(DemoStat),
+    /// Advanced stats
+    Advanced(AdvancedStat),
+    /// Any other stat name, passed through as-is
+    Custom(Cow<'a, str>),
+}
+
+impl Stat<'_> {
+    /// Name of the stat, as used by the API
+    pub fn as_str(&self) -> &str {
+        match self {
+            Stat::Core(stat) => stat.as_str(),
+            Stat::Boost(stat) => stat.as_str(),
+            Stat::Ball(stat) => stat.as_str(),
+            Stat::Movement(stat) => stat.as_str(),
+            Stat::Positioning(stat) => stat.as_str(),
+            Stat::Demo(stat) => stat.as_str(),
+            Stat::Advanced(stat) => stat.as_str(),
+            Stat::Custom(name) => name,
+        }
+    }
+
+    /// Look up a known stat by its API name
+    fn known(name: &str) -> Option<Stat<'static>> {
+        CoreStat::from_name(name)
+            .map(Stat::Core)
+            .or_else(|| BoostStat::from_name(name).map(Stat::Boost))
+            .or_else(|| BallStat::from_name(name).map(Stat::Ball))
+            .or_else(|| MovementStat::from_name(name).map(Stat::Movement))
+            .or_else(|| PositioningStat::from_name(name).map(Stat::Positioning))
+            .or_else(|| DemoStat::from_name(name).map(Stat::Demo))
+            .or_else(|| AdvancedStat::from_name(name).map(Stat::Advanced))
+    }
+}
+
+impl Display for Stat<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AsRef<str> for Stat<'_> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Serialize for Stat<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'a> From<&'a str> for Stat<'a> {
+    fn from(value: &'a str) -> Self {
+        Stat::known(value).unwrap_or(Stat::Custom(value.into()))
+    }
+}
+
+impl From<String> for Stat<'_> {
+    fn from(value: String) -> Self {
+        Stat::known(&value).unwrap_or(Stat::Custom(value.into()))
+    }
+}
+
+impl<'a> From<Cow<'a, str>> for Stat<'a> {
+    fn from(value: Cow<'a, str>) -> Self {
+        Stat::known(&value).unwrap_or(Stat::Custom(value))
+    }
+}
+
+macro_rules! stat_group {
+    (
+        $(#[$meta:meta])*
+        $name:ident => $group:ident {
+            $($(#[$doc:meta])* $variant:ident => $wire:literal,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($(#[$doc])* $variant,)*
+        }
+
+        impl $name {
+            /// All stats in this group
+            pub const ALL: &'static [$name] = &[$($name::$variant,)*];
+
+            /// Name of the stat, as used by the API
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $($name::$variant => $wire,)*
+                }
+            }
+
+            fn from_name(name: &str) -> Option<Self> {
+                match name {
+                    $($wire => Some($name::$variant),)*
+                    _ => None,
+                }
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl From<$name> for &'static str {
+            fn from(value: $name) -> Self {
+                value.as_str()
+            }
+        }
+
+        impl From<$name> for Stat<'_> {
+            fn from(value: $name) -> Self {
+                Stat::$group(value)
+            }
+        }
+    };
+}
+
+stat_group! {
+    /// Core stats. See [`types::CoreStats`](crate::types::CoreStats)
+    CoreStat => Core {
+        /// Shots
+        Shots => "shots",
+        /// Goals
+        Goals => "goals",
+        /// Saves
+        Saves => "saves",
+        /// Assists
+        Assists => "assists",
+        /// Score
+        Score => "score",
+        /// Shooting percentage
+        ShootingPercentage => "shootingPercentage",
+    }
+}
+
+stat_group! {
+    /// Boost stats. See [`types::PlayerBoostStats`](crate::types::PlayerBoostStats)
+    BoostStat => Boost {
+        /// Boost used per minute
+        Bpm => "bpm",
+        /// Boost collected per minute
+        Bcpm => "bcpm",
+        /// Average boost amount
+        AvgAmount => "avgAmount",
+        /// Boost collected
+        AmountCollected => "amountCollected",
+        /// Boost stolen
+        AmountStolen => "amountStolen",
+        /// Boost collected from big pads
+        AmountCollectedBig => "amountCollectedBig",
+        /// Boost stolen from big pads
+        AmountStolenBig => "amountStolenBig",
+        /// Boost collected from small pads
+        AmountCollectedSmall => "amountCollectedSmall",
+        /// Boost stolen from small pads
+        AmountStolenSmall => "amountStolenSmall",
+        /// Big pads collected
+        CountCollectedBig => "countCollectedBig",
+        /// Big pads stolen
+        CountStolenBig => "countStolenBig",
+        /// Small pads collected
+        CountCollectedSmall => "countCollectedSmall",
+        /// Small pads stolen
+        CountStolenSmall => "countStolenSmall",
+        /// Boost overfill
+        AmountOverfill => "amountOverfill",
+        /// Boost overfill from stolen pads
+        AmountOverfillStolen => "amountOverfillStolen",
+        /// Boost used while supersonic
+        AmountUsedWhileSupersonic => "amountUsedWhileSupersonic",
+        /// Time spent with no boost
+        TimeZeroBoost => "timeZeroBoost",
+        /// Percentage of time spent with no boost
+        PercentZeroBoost => "percentZeroBoost",
+        /// Time spent with full boost
+        TimeFullBoost => "timeFullBoost",
+        /// Percentage of time spent with full boost
+        PercentFullBoost => "percentFullBoost",
+    }
+}
+
+stat_group! {
+    /// Ball stats. See [`types::BallStats`](crate::types::BallStats)
+    BallStat => Ball {
+        /// Time in possession of the ball
+        PossessionTime => "possessionTime",
+        /// Time the ball spent in the team's half
+        TimeInSide => "timeInSide",
+    }
+}
+
+stat_group! {
+    /// Movement stats. See [`types::PlayerMovementStats`](crate::types::PlayerMovementStats)
+    MovementStat => Movement {
+        /// Average speed
+        AvgSpeed => "avgSpeed",
+        /// Total distance travelled
+        TotalDistance => "totalDistance",
+        /// Time spent at supersonic speed
+        TimeSupersonicSpeed => "timeSupersonicSpeed",
+        /// Time spent at boost speed
+        TimeBoostSpeed => "timeBoostSpeed",
+        /// Time spent at slow speed
+        TimeSlowSpeed => "timeSlowSpeed",
+        /// Time spent on the ground
+        TimeGround => "timeGround",
+        /// Time spent in low air
+        TimeLowAir => "timeLowAir",
+        /// Time spent in high air
+        TimeHighAir => "timeHighAir",
+        /// Time spent powersliding
+        TimePowerslide => "timePowerslide",
+        /// Number of powerslides
+        CountPowerslide => "countPowerslide",
+        /// Average powerslide duration
+        AvgPowerslideDuration => "avgPowerslideDuration",
+        /// Average speed, as a percentage of supersonic speed
+        AvgSpeedPercentage => "avgSpeedPercentage",
+        /// Percentage of time spent at slow speed
+        PercentSlowSpeed => "percentSlowSpeed",
+        /// Percentage of time spent at boost speed
+        PercentBoostSpeed => "percentBoostSpeed",
+        /// Percentage of time spent at supersonic speed
+        PercentSupersonicSpeed => "percentSupersonicSpeed",
+        /// Percentage of time spent on the ground
+        PercentGround => "percentGround",
+        /// Percentage of time spent in low air
+        PercentLowAir => "percentLowAir",
+        /// Percentage of time spent in high air
+        PercentHighAir => "percentHighAir",
+    }
+}
+
+stat_group! {
+    /// Positioning stats. See [`types::PlayerPositioningStats`](crate::types::PlayerPositioningStats)
+    PositioningStat => Positioning {
+        /// Average distance to the ball
+        AvgDistanceToBall => "avgDistanceToBall",
+        /// Average distance to the ball while in possession
+        AvgDistanceToBallPossession => "avgDistanceToBallPossession",
+        /// Average distance to the ball while not in possession
+        AvgDistanceToBallNoPossession => "avgDistanceToBallNoPossession",
+        /// Average distance to teammates
+        AvgDistanceToMates => "avgDistanceToMates",
+        /// Time spent in the defensive third
+        TimeDefensiveThird => "timeDefensiveThird",
+        /// Time spent in the neutral third
+        TimeNeutralThird => "timeNeutralThird",
+        /// Time spent in the offensive third
+        TimeOffensiveThird => "timeOffensiveThird",
+        /// Time spent in the defensive half
+        TimeDefensiveHalf => "timeDefensiveHalf",
+        /// Time spent in the offensive half
+        TimeOffensiveHalf => "timeOffensiveHalf",
+        /// Time spent behind the ball
+        TimeBehindBall => "timeBehindBall",
+        /// Time spent in front of the ball
+        TimeInfrontBall => "timeInfrontBall",
+        /// Time spent as the last player back
+        TimeMostBack => "timeMostBack",
+        /// Time spent as the most forward player
+        TimeMostForward => "timeMostForward",
+        /// Goals conceded while last defender
+        GoalsAgainstWhileLastDefender => "goalsAgainstWhileLastDefender",
+        /// Time spent closest to the ball
+        TimeClosestToBall => "timeClosestToBall",
+        /// Time spent farthest from the ball
+        TimeFarthestFromBall => "timeFarthestFromBall",
+        /// Percentage of time spent in the defensive third
+        PercentDefensiveThird => "percentDefensiveThird",
+        /// Percentage of time spent in the offensive third
+        PercentOffensiveThird => "percentOffensiveThird",
+        /// Percentage of time spent in the neutral third
+        PercentNeutralThird => "percentNeutralThird",
+        /// Percentage of time spent in the defensive half
+        PercentDefensiveHalf => "percentDefensiveHalf",
+        /// Percentage of time spent in the offensive half
+        PercentOffensiveHalf => "percentOffensiveHalf",
+        /// Percentage of time spent behind the ball
+        PercentBehindBall => "percentBehindBall",
+        /// Percentage of time spent in front of the ball
+        PercentInfrontBall => "percentInfrontBall",
+        /// Percentage of time spent as the last player back
+        PercentMostBack => "percentMostBack",
+        /// Percentage of time spent as the most forward player
+        PercentMostForward => "percentMostForward",
+        /// Percentage of time spent closest to the ball
+        PercentClosestToBall => "percentClosestToBall",
+        /// Percentage of time spent farthest from the ball
+        PercentFarthestFromBall => "percentFarthestFromBall",
+    }
+}
+
+stat_group! {
+    /// Demolition stats. See [`types::DemoStats`](crate::types::DemoStats)
+    DemoStat => Demo {
+        /// Demolitions inflicted
+        Inflicted => "inflicted",
+        /// Demolitions taken
+        Taken => "taken",
+    }
+}
+
+stat_group! {
+    /// Advanced stats. See [`types::AdvancedStats`](crate::types::AdvancedStats)
+    AdvancedStat => Advanced {
+        /// Goal participation
+        GoalParticipation => "goalParticipation",
+        /// Player rating
+        Rating => "rating",
+        /// Most valuable player
+        Mvp => "mvp",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn known_names_round_trip() {
+        let groups: [Vec<Stat<'_>>; 7] = [
+            CoreStat::ALL.iter().map(|&s| s.into()).collect(),
+            BoostStat::ALL.iter().map(|&s| s.into()).collect(),
+            BallStat::ALL.iter().map(|&s| s.into()).collect(),
+            MovementStat::ALL.iter().map(|&s| s.into()).collect(),
+            PositioningStat::ALL.iter().map(|&s| s.into()).collect(),
+            DemoStat::ALL.iter().map(|&s| s.into()).collect(),
+            AdvancedStat::ALL.iter().map(|&s| s.into()).collect(),
+        ];
+
+        for stat in groups.into_iter().flatten() {
+            assert_eq!(Stat::from(stat.as_str()), stat);
+        }
+    }
+
+    #[test]
+    fn custom_names() {
+        let stat = Stat::from("shootingPercentge");
+        assert_eq!(stat, Stat::Custom("shootingPercentge".into()));
+        assert_eq!(stat.to_string(), "shootingPercentge");
+        assert_eq!(Stat::from(String::from("bpm")), Stat::Boost(BoostStat::Bpm));
+    }
+}
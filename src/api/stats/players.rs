@@ -17,6 +17,7 @@ use crate::api::{
     players::PlayerId,
     query_params::QueryParams,
     stages::StageId,
+    stat::Stat,
     teams::TeamId,
 };
 
@@ -25,39 +26,61 @@ use crate::api::{
 #[serde(rename_all = "camelCase")]
 #[builder(setter(into, strip_option))]
 pub struct PlayerStats<'a> {
-    #[doc = "Stat names. Example: `\"score\"`"]
-    stat: Cow<'a, str>,
+    #[doc = "Stat names. Example: `CoreStat::Score`"]
+    #[serde(rename = "stat")]
+    #[builder(setter(each(name = "stat", into)))]
+    stats: Vec<Stat<'a>>,
     #[doc = "An event ID. Example: `\"5f35882d53fbbb5894b43040\"`"]
+    #[builder(default)]
     event: Option<EventId<'a>>,
     #[doc = "A stage ID. Example: `1`"]
+    #[builder(default)]
     stage: Option<StageId>,
     #[doc = "A match ID. Example: `\"6043152fa09e7fba40d2ae62\"`"]
     #[serde(rename = "match")]
+    #[builder(default)]
     match_id: Option<MatchId<'a>>,
     #[doc = "Is stage a qualifier. Example: `true`"]
+    #[builder(default)]
     qualifier: Option<bool>,
     #[doc = "Game or series winner. Example: `true`"]
+    #[builder(default)]
     winner: Option<bool>,
     #[doc = "A 2-letter country code. Example: `\"us\"`"]
+    #[builder(default)]
     nationality: Option<Cow<'a, str>>,
-    #[doc = "Event tier. Example `Tier::S`"]
-    tier: Option<Tier>,
-    #[doc = "Event region. Example: `Region::NA`"]
-    region: Option<Region>,
-    #[doc = "Event mode. Example: `Mode::Three`"]
-    mode: Option<Mode>,
+    #[doc = "Event tiers. Example `Tier::S`"]
+    #[serde(rename = "tier")]
+    #[builder(default, setter(each(name = "tier")))]
+    tiers: Vec<Tier>,
+    #[doc = "Event regions. Example: `Region::NA`"]
+    #[serde(rename = "region")]
+    #[builder(default, setter(each(name = "region")))]
+    regions: Vec<Region>,
+    #[doc = "Event modes. Example: `Mode::Three`"]
+    #[serde(rename = "mode")]
+    #[builder(default, setter(each(name = "mode")))]
+    modes: Vec<Mode>,
     #[doc = "Event group. Example: `\"rlcsx\"`"]
+    #[builder(default)]
     group: Option<Cow<'a, str>>,
     #[doc = "Filter events before this date. Example: `\"2016-12-03\".parse()`"]
+    #[builder(default)]
     before: Option<DateTime<Utc>>,
     #[doc = "Filter events after this date. Example: `\"2016-12-03\".parse()`"]
+    #[builder(default)]
     after: Option<DateTime<Utc>>,
     #[doc = "A match format. Example: `BestOf::Five`"]
+    #[builder(default)]
     best_of: Option<BestOf>,
-    #[doc = "A player ID. Example: `\"5f3d8fdd95f40596eae23d97\"`"]
-    player: Option<PlayerId<'a>>,
-    #[doc = "A team ID. Example: `\"6020bc70f1e4807cc70023c7\"`"]
-    team: Option<TeamId<'a>>,
+    #[doc = "Player IDs. Example: `\"5f3d8fdd95f40596eae23d97\"`"]
+    #[serde(rename = "player")]
+    #[builder(default, setter(each(name = "player", into)))]
+    players: Vec<PlayerId<'a>>,
+    #[doc = "Team IDs. Example: `\"6020bc70f1e4807cc70023c7\"`"]
+    #[serde(rename = "team")]
+    #[builder(default, setter(each(name = "team", into)))]
+    teams: Vec<TeamId<'a>>,
 }
 
 impl PlayerStats<'_> {
@@ -76,3 +99,59 @@ impl Endpoint for PlayerStats<'_> {
         QueryParams::with(self)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        api::{Collection, CoreStat, Query},
+        mock::{Expectation, MockClient},
+        types::PlayerStatline,
+    };
+
+    #[test]
+    fn typed_response() {
+        let client = MockClient::new();
+        client.expect(
+            Expectation::get("/stats/players")
+                .query("stat=score&stat=goals&player=5f3d8fdd95f40596eae23d97")
+                .json(&json!({
+                    "stats": [{
+                        "player": {"_id": "5f3d8fdd95f40596eae23d97", "tag": "Kronovi"},
+                        "startDate": "2016-03-19T00:00:00Z",
+                        "games": {"total": 10, "replays": 8, "wins": 6},
+                        "matches": {"total": 3, "replays": 3, "wins": 2},
+                        "stats": {"score": 450.5, "goals": 1.25}
+                    }]
+                })),
+        );
+
+        let endpoint = PlayerStats::builder()
+            .stat(CoreStat::Score)
+            .stat("goals")
+            .player("5f3d8fdd95f40596eae23d97")
+            .build()
+            .unwrap();
+        let stats: Collection<PlayerStatline<'_>> = endpoint.query(&client).unwrap();
+
+        let statline = &stats.inner[0];
+        assert_eq!(statline.player.tag, "Kronovi");
+        assert_eq!(statline.games.wins, 6);
+        assert_eq!(statline.average("score"), Some(450.5));
+        assert_eq!(statline.total("score"), Some(3604.0));
+        assert_eq!(statline.average(CoreStat::Goals), Some(1.25));
+        assert_eq!(statline.total(CoreStat::Goals), Some(10.0));
+        assert_eq!(statline.average(CoreStat::Saves), None);
+    }
+
+    #[test]
+    fn stat_is_required() {
+        let err = PlayerStats::builder().player("abc").build().unwrap_err();
+        assert!(matches!(
+            err,
+            PlayerStatsBuilderError::UninitializedField("stats")
+        ));
+    }
+}
@@ -10,9 +10,12 @@ use serde::Serialize;
 use crate::api::{
     common::{BestOf, Mode, Region, Tier},
     endpoint::Endpoint,
+    error::BodyError,
     events::EventId,
     matches::MatchId,
+    query_params::QueryParams,
     stages::StageId,
+    stat::Stat,
     teams::TeamId,
 };
 
@@ -21,37 +24,57 @@ use crate::api::{
 #[serde(rename_all = "camelCase")]
 #[builder(setter(into, strip_option))]
 pub struct TeamStats<'a> {
-    #[doc = "Stat names. Example: `\"score\"`"]
-    stat: Cow<'a, str>,
+    #[doc = "Stat names. Example: `CoreStat::Score`"]
+    #[serde(rename = "stat")]
+    #[builder(setter(each(name = "stat", into)))]
+    stats: Vec<Stat<'a>>,
     #[doc = "An event ID. Example: `\"5f35882d53fbbb5894b43040\"`"]
+    #[builder(default)]
     event: Option<EventId<'a>>,
     #[doc = "A stage ID. Example: `1`"]
+    #[builder(default)]
     stage: Option<StageId>,
     #[doc = "A match ID. Example: `\"6043152fa09e7fba40d2ae62\"`"]
     #[serde(rename = "match")]
+    #[builder(default)]
     match_id: Option<MatchId<'a>>,
     #[doc = "Is stage a qualifier. Example: `true`"]
+    #[builder(default)]
     qualifier: Option<bool>,
     #[doc = "Game or series winner. Example: `true`"]
+    #[builder(default)]
     winner: Option<bool>,
     #[doc = "A 2-letter country code. Example: `\"us\"`"]
+    #[builder(default)]
     nationality: Option<Cow<'a, str>>,
-    #[doc = "Event tier. Example `Tier::S`"]
-    tier: Option<Tier>,
-    #[doc = "Event region. Example: `Region::NA`"]
-    region: Option<Region>,
-    #[doc = "Event mode. Example: `Mode::Three`"]
-    mode: Option<Mode>,
+    #[doc = "Event tiers. Example `Tier::S`"]
+    #[serde(rename = "tier")]
+    #[builder(default, setter(each(name = "tier")))]
+    tiers: Vec<Tier>,
+    #[doc = "Event regions. Example: `Region::NA`"]
+    #[serde(rename = "region")]
+    #[builder(default, setter(each(name = "region")))]
+    regions: Vec<Region>,
+    #[doc = "Event modes. Example: `Mode::Three`"]
+    #[serde(rename = "mode")]
+    #[builder(default, setter(each(name = "mode")))]
+    modes: Vec<Mode>,
     #[doc = "Event group. Example: `\"rlcsx\"`"]
+    #[builder(default)]
     group: Option<Cow<'a, str>>,
     #[doc = "Filter events before this date. Example: `\"2016-12-03\".parse()`"]
+    #[builder(default)]
     before: Option<DateTime<Utc>>,
     #[doc = "Filter events after this date. Example: `\"2016-12-03\".parse()`"]
+    #[builder(default)]
     after: Option<DateTime<Utc>>,
     #[doc = "A match format. Example: `BestOf::Five`"]
+    #[builder(default)]
     best_of: Option<BestOf>,
-    #[doc = "A team ID. Example: `\"6020bc70f1e4807cc70023c7\"`"]
-    team: Option<TeamId<'a>>,
+    #[doc = "Team IDs. Example: `\"6020bc70f1e4807cc70023c7\"`"]
+    #[serde(rename = "team")]
+    #[builder(default, setter(each(name = "team", into)))]
+    teams: Vec<TeamId<'a>>,
 }
 
 impl TeamStats<'_> {
@@ -65,4 +88,40 @@ impl Endpoint for TeamStats<'_> {
     fn endpoint(&self) -> Cow<'static, str> {
         "/stats/teams".into()
     }
+
+    fn query_parameters(&self) -> Result<QueryParams<'_>, BodyError> {
+        QueryParams::with(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        api::Query,
+        mock::{Expectation, MockClient},
+    };
+
+    #[test]
+    fn unset_filters_are_omitted() {
+        let client = MockClient::new();
+        client.expect(
+            Expectation::get("/stats/teams")
+                .query("stat=score&team=6020bc70f1e4807cc70023c7")
+                .json(&json!({ "stats": [] })),
+        );
+
+        // Without defaults the builder fails on the unset filters, and without
+        // `query_parameters` the `stat` and `team` filters are never sent.
+        let endpoint = TeamStats::builder()
+            .stat("score")
+            .team("6020bc70f1e4807cc70023c7")
+            .build()
+            .unwrap();
+        let _: serde_json::Value = endpoint.query(&client).unwrap();
+
+        client.assert_requests(&["/stats/teams?stat=score&team=6020bc70f1e4807cc70023c7"]);
+    }
 }
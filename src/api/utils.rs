@@ -1,6 +1,10 @@
+use std::time::Duration;
+
 use bytes::Bytes;
-use http::{header, request::Builder as RequestBuilder, Request, Response, StatusCode};
+use chrono::{DateTime, Utc};
+use http::{header, request::Builder as RequestBuilder, HeaderMap, Request, Response, StatusCode};
 use serde::{de::DeserializeOwned, Serializer};
+use serde_path_to_error::Segment;
 use thiserror::Error;
 
 use super::{common::Direction, endpoint::Endpoint, ApiError, RestClient};
@@ -34,41 +38,181 @@ where
     Ok((http_req, body))
 }
 
+/// The response body does not match the expected type
+#[derive(Debug, Error)]
+#[error("Deserializing {typ} at `{path}`: {source}, found {snippet}")]
+pub struct DataTypeError {
+    /// Source of the error
+    pub source: serde_json::Error,
+    /// The response body
+    pub value: serde_json::Value,
+    /// Name of the expected type
+    pub typ: &'static str,
+    /// Path to the offending value. Example:
+    /// `games[3].blue.players[0].stats.core.score`
+    pub path: String,
+    /// The offending value as JSON, truncated to a few characters
+    pub snippet: String,
+}
+
+/// Errors in the response to a request
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum ResponseError {
+    /// The response body is not valid JSON
     #[error("Parsing JSON: {0}")]
     Parse(#[from] serde_json::Error),
-    #[error("Deserializing value: {source}")]
-    DataType {
-        source: serde_json::Error,
-        value: serde_json::Value,
-        typ: &'static str,
+    /// The response body does not match the expected type
+    #[error(transparent)]
+    DataType(Box<DataTypeError>),
+    /// The requested resource does not exist, e.g. an unknown id
+    #[error("Not found: {message}")]
+    NotFound {
+        /// Error message from the server
+        message: String,
+    },
+    /// The server rejected the request
+    #[error("Bad request: {message}")]
+    BadRequest {
+        /// Error message from the server
+        message: String,
+    },
+    /// Too many requests were made
+    #[error("Rate limited")]
+    RateLimited {
+        /// Delay requested by the `Retry-After` header
+        retry_after: Option<Duration>,
+    },
+    /// The server failed to handle the request
+    #[error("Server error: {status}")]
+    ServerError {
+        /// HTTP status of the response
+        status: StatusCode,
+        /// The response body, which may not be JSON
+        body: String,
     },
+    /// Any other unsuccessful HTTP status
     #[error("HTTP error: {status}")]
     HttpStatus {
+        /// The response body, or the body text as a string if it is not JSON
         value: serde_json::Value,
+        /// HTTP status of the response
         status: StatusCode,
     },
 }
 
-pub(crate) fn deserialize_response<T, C>(rsp: Response<Bytes>) -> Result<T, ResponseError>
+impl ResponseError {
+    /// Convert an unsuccessful response to an error
+    fn from_status(rsp: &Response<Bytes>) -> Self {
+        let status = rsp.status();
+        let body = String::from_utf8_lossy(rsp.body()).into_owned();
+
+        match status {
+            StatusCode::NOT_FOUND => Self::NotFound {
+                message: error_message(&body),
+            },
+            StatusCode::BAD_REQUEST => Self::BadRequest {
+                message: error_message(&body),
+            },
+            StatusCode::TOO_MANY_REQUESTS => Self::RateLimited {
+                retry_after: retry_after(rsp.headers()),
+            },
+            status if status.is_server_error() => Self::ServerError { status, body },
+            status => Self::HttpStatus {
+                value: serde_json::from_str(&body).unwrap_or(serde_json::Value::String(body)),
+                status,
+            },
+        }
+    }
+}
+
+/// Extract the message from a ZSR error body, e.g. `{"message": "..."}`,
+/// falling back to the body text
+fn error_message(body: &str) -> String {
+    let message = serde_json::from_str::<serde_json::Value>(body)
+        .ok()
+        .and_then(|value| {
+            ["message", "error"]
+                .iter()
+                .find_map(|key| value.get(key)?.as_str().map(str::to_owned))
+        });
+
+    message.unwrap_or_else(|| body.trim().to_owned())
+}
+
+/// Parse a `Retry-After` header given either in seconds or as an HTTP date
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(header::RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (date.with_timezone(&Utc) - Utc::now())
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
+pub(crate) fn deserialize_response<T>(rsp: Response<Bytes>) -> Result<T, ResponseError>
 where
     T: DeserializeOwned,
-    C: RestClient,
 {
-    let status = rsp.status();
-    let json_value = serde_json::from_slice(rsp.body())?;
-    if !status.is_success() {
-        return Err(ResponseError::HttpStatus {
+    if !rsp.status().is_success() {
+        return Err(ResponseError::from_status(&rsp));
+    }
+
+    let json_value: serde_json::Value = serde_json::from_slice(rsp.body())?;
+    serde_path_to_error::deserialize(&json_value).map_err(|err| {
+        let path = err.path().clone();
+        ResponseError::DataType(Box::new(DataTypeError {
+            source: err.into_inner(),
+            snippet: snippet(&json_value, &path),
             value: json_value,
-            status,
-        });
+            typ: std::any::type_name::<T>(),
+            path: path.to_string(),
+        }))
+    })
+}
+
+/// Maximum length of the value snippet in deserialization errors
+const SNIPPET_LEN: usize = 120;
+
+/// The JSON of the value at `path` in `value`, or of its closest parent,
+/// truncated to [`SNIPPET_LEN`] bytes
+fn snippet(value: &serde_json::Value, path: &serde_path_to_error::Path) -> String {
+    let mut value = value;
+    for segment in path {
+        let child = match segment {
+            Segment::Seq { index } => value.get(index),
+            Segment::Map { key } => value.get(key),
+            Segment::Enum { variant } => value.get(variant),
+            Segment::Unknown => None,
+        };
+        match child {
+            Some(child) => value = child,
+            None => break,
+        }
+    }
+
+    let mut snippet = value.to_string();
+    if snippet.len() > SNIPPET_LEN {
+        let end = (0..=SNIPPET_LEN)
+            .rev()
+            .find(|&end| snippet.is_char_boundary(end))
+            .unwrap_or_default();
+        snippet.truncate(end);
+        snippet.push('…');
     }
+    snippet
+}
 
-    serde_json::from_value(json_value.clone()).map_err(|err| ResponseError::DataType {
-        source: err,
-        value: json_value,
-        typ: std::any::type_name::<T>(),
+/// 64-bit FNV-1a hash, used for file names that are stable across builds
+pub(crate) fn fnv1a(data: &[u8]) -> u64 {
+    data.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
     })
 }
 
@@ -87,3 +231,123 @@ where
         serializer.serialize_none()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::{
+        api::{events::Event, Query},
+        mock::{Expectation, MockClient},
+    };
+
+    fn response(status: u16) -> http::response::Builder {
+        Response::builder().status(status)
+    }
+
+    fn error(rsp: http::response::Builder, body: &'static str) -> ResponseError {
+        deserialize_response::<Value>(rsp.body(Bytes::from_static(body.as_bytes())).unwrap())
+            .unwrap_err()
+    }
+
+    #[test]
+    fn not_found() {
+        let client = MockClient::new();
+        client.expect(
+            Expectation::get("/events/unknown")
+                .status(404)
+                .json(&json!({"message": "event not found"})),
+        );
+
+        let endpoint = Event::builder().id("unknown").build().unwrap();
+        let err = Query::<Value, _>::query(&endpoint, &client).unwrap_err();
+
+        assert!(matches!(
+            err,
+            ApiError::Response(err) if matches!(
+                &err.source,
+                ResponseError::NotFound { message } if message == "event not found"
+            )
+        ));
+    }
+
+    #[test]
+    fn bad_request_message() {
+        let err = error(response(400), r#"{"error": "invalid stat"}"#);
+        assert!(matches!(err, ResponseError::BadRequest { message } if message == "invalid stat"));
+
+        let err = error(response(400), "invalid stat\n");
+        assert!(matches!(err, ResponseError::BadRequest { message } if message == "invalid stat"));
+    }
+
+    #[test]
+    fn rate_limited() {
+        let rsp = response(429).header(header::RETRY_AFTER, "30");
+        let err = error(rsp, "");
+
+        assert!(matches!(
+            err,
+            ResponseError::RateLimited { retry_after: Some(delay) } if delay == Duration::from_secs(30)
+        ));
+    }
+
+    #[test]
+    fn data_type_path() {
+        #[derive(Debug, serde::Deserialize)]
+        struct Player {
+            #[allow(dead_code)]
+            score: f64,
+        }
+
+        let body = r#"{"players": [{"score": 1}, {"score": "high"}]}"#;
+        let err = deserialize_response::<BTreeMap<String, Vec<Player>>>(
+            response(200)
+                .body(Bytes::from_static(body.as_bytes()))
+                .unwrap(),
+        )
+        .unwrap_err();
+
+        let ResponseError::DataType(data_type) = &err else {
+            panic!("unexpected error: {}", err);
+        };
+        assert_eq!(data_type.path, "players[1].score");
+        assert_eq!(data_type.snippet, r#""high""#);
+        assert!(err.to_string().contains("at `players[1].score`"));
+    }
+
+    #[test]
+    fn snippet_is_truncated() {
+        let body = json!({"names": ["é".repeat(100)]}).to_string();
+        let err = deserialize_response::<u32>(response(200).body(Bytes::from(body)).unwrap())
+            .unwrap_err();
+
+        let ResponseError::DataType(data_type) = err else {
+            panic!("unexpected error: {}", err);
+        };
+        let snippet = data_type.snippet;
+        assert!(snippet.len() <= SNIPPET_LEN + '…'.len_utf8());
+        assert!(snippet.starts_with(r#"{"names":["éé"#));
+        assert!(snippet.ends_with('…'));
+    }
+
+    #[test]
+    fn server_error_keeps_body() {
+        let err = error(response(502), "<html>Bad Gateway</html>");
+
+        assert!(matches!(
+            err,
+            ResponseError::ServerError { status, body }
+                if status == StatusCode::BAD_GATEWAY && body == "<html>Bad Gateway</html>"
+        ));
+    }
+
+    #[test]
+    fn fnv1a_known_values() {
+        assert_eq!(fnv1a(b""), 0xcbf29ce484222325);
+        assert_eq!(fnv1a(b"a"), 0xaf63dc4c8601ec8c);
+        assert_eq!(fnv1a(b"foobar"), 0x85944171f73967e8);
+    }
+}
@@ -0,0 +1,600 @@
+//! Response caching
+//!
+//! Clients built with [`OctaneZsrBuilder::cache`] store successful `GET`
+//! responses in a [`Cache`] backend, keyed by the full request URL. How long a
+//! response stays fresh is decided by a [`CachePolicy`]. Once a response
+//! expires it is revalidated with `If-None-Match`/`If-Modified-Since` if the
+//! server sent an `ETag` or `Last-Modified` header, and re-downloaded
+//! otherwise.
+//!
+//! # Example
+//!
+#![cfg_attr(feature = "blocking", doc = "```rust ,no_run")]
+#![cfg_attr(not(feature = "blocking"), doc = "```rust ,ignore")]
+//! use std::time::Duration;
+//!
+//! use octanezsr_api::{
+//!     cache::{CachePolicy, MemoryCache},
+//!     OctaneZsrBuilder,
+//! };
+//!
+//! # fn main() -> octanezsr_api::error::OctaneZsrResult<()> {
+//! // Cache responses for 5 minutes, but games for a day
+//! let policy = CachePolicy::new(Duration::from_secs(5 * 60))
+//!     .ttl("games", Duration::from_secs(24 * 60 * 60));
+//! let client = OctaneZsrBuilder::new()
+//!     .cache(MemoryCache::new(1000), policy)
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`OctaneZsrBuilder::cache`]: crate::OctaneZsrBuilder::cache
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Debug,
+    fs, io,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime},
+};
+
+use bytes::Bytes;
+use http::{header, HeaderMap, HeaderValue, Method, Response, StatusCode};
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::api::utils::fnv1a;
+
+/// A storage backend for cached responses
+///
+/// Caching is best-effort; backends should log and swallow their own errors.
+pub trait Cache: Send + Sync {
+    /// Get the response stored for `key`
+    fn get(&self, key: &str) -> Option<CachedResponse>;
+
+    /// Store a response for `key`, replacing any previous response
+    fn put(&self, key: &str, response: CachedResponse);
+
+    /// Remove the response stored for `key`
+    fn remove(&self, key: &str);
+}
+
+/// A response stored in a [`Cache`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachedResponse {
+    /// HTTP status code
+    pub status: StatusCode,
+    /// Response headers
+    pub headers: HeaderMap,
+    /// Response body
+    pub body: Bytes,
+    /// Time after which the response must be revalidated
+    pub expires: SystemTime,
+}
+
+impl CachedResponse {
+    fn new(response: &Response<Bytes>, ttl: Duration) -> Self {
+        Self {
+            status: response.status(),
+            headers: response.headers().clone(),
+            body: response.body().clone(),
+            expires: SystemTime::now() + ttl,
+        }
+    }
+
+    /// Has this response expired
+    pub fn is_expired(&self) -> bool {
+        self.expires <= SystemTime::now()
+    }
+
+    fn etag(&self) -> Option<&HeaderValue> {
+        self.headers.get(header::ETAG)
+    }
+
+    fn last_modified(&self) -> Option<&HeaderValue> {
+        self.headers.get(header::LAST_MODIFIED)
+    }
+
+    fn to_response(&self) -> Response<Bytes> {
+        let mut response = Response::new(self.body.clone());
+        *response.status_mut() = self.status;
+        *response.headers_mut() = self.headers.clone();
+        response
+    }
+}
+
+/// Decides how long responses are cached for, by endpoint
+///
+/// Rules match the start of the endpoint path, e.g. `"events"` matches
+/// `/events`, `/events/{id}` and `/events/{id}/matches`. The longest matching
+/// rule wins.
+#[derive(Debug, Clone)]
+pub struct CachePolicy {
+    default_ttl: Option<Duration>,
+    rules: Vec<(String, Option<Duration>)>,
+}
+
+impl CachePolicy {
+    /// Create a new [`CachePolicy`] that caches every endpoint for
+    /// `default_ttl`
+    pub fn new(default_ttl: Duration) -> Self {
+        Self {
+            default_ttl: Some(default_ttl),
+            rules: Vec::new(),
+        }
+    }
+
+    /// Create a new [`CachePolicy`] that only caches endpoints with an
+    /// explicit [`ttl`](Self::ttl)
+    pub fn none() -> Self {
+        Self {
+            default_ttl: None,
+            rules: Vec::new(),
+        }
+    }
+
+    /// Cache endpoints starting with `path` for `ttl`. A `ttl` of `None`
+    /// disables caching for these endpoints. Example: `"stats"`
+    pub fn ttl<P, T>(mut self, path: P, ttl: T) -> Self
+    where
+        P: AsRef<str>,
+        T: Into<Option<Duration>>,
+    {
+        let path = path.as_ref().trim_matches('/').to_string();
+        self.rules.push((path, ttl.into()));
+        self
+    }
+
+    fn ttl_for(&self, path: &str) -> Option<Duration> {
+        let path = path.trim_matches('/');
+        self.rules
+            .iter()
+            .filter(|(prefix, _)| {
+                prefix.is_empty()
+                    || path == prefix
+                    || path
+                        .strip_prefix(prefix.as_str())
+                        .is_some_and(|rest| rest.starts_with('/'))
+            })
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map_or(self.default_ttl, |(_, ttl)| *ttl)
+    }
+}
+
+impl Default for CachePolicy {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(5 * 60))
+    }
+}
+
+/// An in-memory [`Cache`] that evicts the least recently used response once
+/// it is full
+#[derive(Debug)]
+pub struct MemoryCache {
+    capacity: usize,
+    inner: Mutex<MemoryCacheInner>,
+}
+
+#[derive(Debug, Default)]
+struct MemoryCacheInner {
+    tick: u64,
+    entries: HashMap<String, (CachedResponse, u64)>,
+    recency: BTreeMap<u64, String>,
+}
+
+impl MemoryCache {
+    /// Create a new [`MemoryCache`] holding up to `capacity` responses
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            inner: Mutex::new(MemoryCacheInner::default()),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, MemoryCacheInner> {
+        self.inner.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+impl MemoryCacheInner {
+    fn touch(&mut self, key: &str) -> Option<&CachedResponse> {
+        self.tick += 1;
+        let tick = self.tick;
+        let (response, last_used) = self.entries.get_mut(key)?;
+        self.recency.remove(last_used);
+        self.recency.insert(tick, key.to_string());
+        *last_used = tick;
+        Some(response)
+    }
+}
+
+impl Cache for MemoryCache {
+    fn get(&self, key: &str) -> Option<CachedResponse> {
+        self.lock().touch(key).cloned()
+    }
+
+    fn put(&self, key: &str, response: CachedResponse) {
+        let mut inner = self.lock();
+
+        if inner.touch(key).is_none() && inner.entries.len() >= self.capacity {
+            if let Some((_, oldest)) = inner.recency.pop_first() {
+                inner.entries.remove(&oldest);
+            }
+        }
+
+        let tick = inner.tick;
+        inner.recency.insert(tick, key.to_string());
+        inner.entries.insert(key.to_string(), (response, tick));
+    }
+
+    fn remove(&self, key: &str) {
+        let mut inner = self.lock();
+        if let Some((_, last_used)) = inner.entries.remove(key) {
+            inner.recency.remove(&last_used);
+        }
+    }
+}
+
+/// A [`Cache`] storing responses as files in a directory
+///
+/// Each response is stored as a `.json` metadata file and a `.body` file,
+/// named after a hash of the request URL.
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    dir: PathBuf,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct DiskCacheEntry {
+    key: String,
+    status: u16,
+    headers: Vec<(String, String)>,
+    expires: u64,
+}
+
+impl DiskCache {
+    /// Create a new [`DiskCache`] in `dir`, creating the directory if needed
+    pub fn new<P>(dir: P) -> io::Result<Self>
+    where
+        P: Into<PathBuf>,
+    {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    fn paths(&self, key: &str) -> (PathBuf, PathBuf) {
+        let name = format!("{:016x}", fnv1a(key.as_bytes()));
+        (
+            self.dir.join(format!("{}.json", name)),
+            self.dir.join(format!("{}.body", name)),
+        )
+    }
+
+    fn read(&self, key: &str) -> io::Result<Option<CachedResponse>> {
+        let (meta_path, body_path) = self.paths(key);
+
+        let entry: DiskCacheEntry = match fs::read(&meta_path) {
+            Ok(data) => serde_json::from_slice(&data)?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        // Hash collision
+        if entry.key != key {
+            return Ok(None);
+        }
+
+        let mut headers = HeaderMap::new();
+        for (name, value) in entry.headers {
+            if let (Ok(name), Ok(value)) = (
+                header::HeaderName::from_bytes(name.as_bytes()),
+                HeaderValue::from_str(&value),
+            ) {
+                headers.append(name, value);
+            }
+        }
+
+        Ok(Some(CachedResponse {
+            status: StatusCode::from_u16(entry.status)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?,
+            headers,
+            body: fs::read(body_path)?.into(),
+            expires: SystemTime::UNIX_EPOCH + Duration::from_secs(entry.expires),
+        }))
+    }
+
+    fn write(&self, key: &str, response: &CachedResponse) -> io::Result<()> {
+        let (meta_path, body_path) = self.paths(key);
+
+        let entry = DiskCacheEntry {
+            key: key.to_string(),
+            status: response.status.as_u16(),
+            headers: response
+                .headers
+                .iter()
+                .filter_map(|(name, value)| {
+                    Some((name.to_string(), value.to_str().ok()?.to_string()))
+                })
+                .collect(),
+            expires: response
+                .expires
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+        };
+
+        fs::write(body_path, &response.body)?;
+        fs::write(meta_path, serde_json::to_vec(&entry)?)
+    }
+}
+
+impl Cache for DiskCache {
+    fn get(&self, key: &str) -> Option<CachedResponse> {
+        self.read(key).unwrap_or_else(|err| {
+            warn!("failed to read cached response for {}: {}", key, err);
+            None
+        })
+    }
+
+    fn put(&self, key: &str, response: CachedResponse) {
+        if let Err(err) = self.write(key, &response) {
+            warn!("failed to cache response for {}: {}", key, err);
+        }
+    }
+
+    fn remove(&self, key: &str) {
+        let (meta_path, body_path) = self.paths(key);
+        for path in [meta_path, body_path] {
+            if let Err(err) = fs::remove_file(path) {
+                if err.kind() != io::ErrorKind::NotFound {
+                    warn!("failed to remove cached response for {}: {}", key, err);
+                }
+            }
+        }
+    }
+}
+
+/// A [`Cache`] backend combined with its [`CachePolicy`]
+#[derive(Clone)]
+pub(crate) struct ResponseCache {
+    backend: Arc<dyn Cache>,
+    policy: CachePolicy,
+}
+
+impl Debug for ResponseCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResponseCache")
+            .field("policy", &self.policy)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Result of looking up a request in a [`ResponseCache`]
+pub(crate) enum Lookup {
+    /// The request can not be cached
+    Skip,
+    /// A fresh response was found
+    Hit(Response<Bytes>),
+    /// The request has to be sent to the server
+    Miss {
+        ttl: Duration,
+        stale: Option<CachedResponse>,
+    },
+}
+
+impl ResponseCache {
+    pub(crate) fn new<C>(backend: C, policy: CachePolicy) -> Self
+    where
+        C: Cache + 'static,
+    {
+        Self {
+            backend: Arc::new(backend),
+            policy,
+        }
+    }
+
+    /// Look up a request to `url`, a URL below `rest_url`.
+    ///
+    /// Conditional request headers are added to `headers` if a stale response
+    /// can be revalidated.
+    pub(crate) fn lookup(
+        &self,
+        method: &Method,
+        url: &Url,
+        rest_url: &Url,
+        headers: &mut HeaderMap,
+    ) -> Lookup {
+        if method != Method::GET {
+            return Lookup::Skip;
+        }
+
+        let path = url
+            .path()
+            .strip_prefix(rest_url.path())
+            .unwrap_or_else(|| url.path());
+        let Some(ttl) = self.policy.ttl_for(path) else {
+            return Lookup::Skip;
+        };
+
+        let stale = match self.backend.get(url.as_str()) {
+            Some(cached) if !cached.is_expired() => {
+                debug!("cache hit for {}", url);
+                return Lookup::Hit(cached.to_response());
+            }
+            Some(cached) if cached.etag().is_some() || cached.last_modified().is_some() => {
+                if let Some(etag) = cached.etag() {
+                    headers.insert(header::IF_NONE_MATCH, etag.clone());
+                }
+                if let Some(last_modified) = cached.last_modified() {
+                    headers.insert(header::IF_MODIFIED_SINCE, last_modified.clone());
+                }
+                Some(cached)
+            }
+            _ => None,
+        };
+
+        Lookup::Miss { ttl, stale }
+    }
+
+    /// Store the `response` to a request that missed the cache, returning the
+    /// response to hand to the caller.
+    pub(crate) fn store(
+        &self,
+        url: &Url,
+        ttl: Duration,
+        stale: Option<CachedResponse>,
+        response: Response<Bytes>,
+    ) -> Response<Bytes> {
+        match stale {
+            Some(mut cached) if response.status() == StatusCode::NOT_MODIFIED => {
+                debug!("revalidated cached response for {}", url);
+                for (name, value) in response.headers() {
+                    cached.headers.insert(name, value.clone());
+                }
+                cached.expires = SystemTime::now() + ttl;
+                self.backend.put(url.as_str(), cached.clone());
+                cached.to_response()
+            }
+            _ if response.status().is_success() => {
+                self.backend
+                    .put(url.as_str(), CachedResponse::new(&response, ttl));
+                response
+            }
+            _ => response,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn cached(body: &'static str, ttl: Duration) -> CachedResponse {
+        CachedResponse::new(&Response::new(Bytes::from_static(body.as_bytes())), ttl)
+    }
+
+    #[test]
+    fn policy_longest_prefix() {
+        let policy = CachePolicy::new(Duration::from_secs(1))
+            .ttl("events", Duration::from_secs(10))
+            .ttl("/events/active/", None)
+            .ttl("stats/players", Duration::from_secs(20));
+
+        assert_eq!(policy.ttl_for("/teams"), Some(Duration::from_secs(1)));
+        assert_eq!(policy.ttl_for("events"), Some(Duration::from_secs(10)));
+        assert_eq!(policy.ttl_for("/events/abc"), Some(Duration::from_secs(10)));
+        assert_eq!(policy.ttl_for("/events/active"), None);
+        assert_eq!(policy.ttl_for("/eventsfoo"), Some(Duration::from_secs(1)));
+        assert_eq!(
+            policy.ttl_for("/stats/players/events"),
+            Some(Duration::from_secs(20))
+        );
+        assert_eq!(CachePolicy::none().ttl_for("/events"), None);
+    }
+
+    #[test]
+    fn memory_cache_evicts_least_recently_used() {
+        let cache = MemoryCache::new(2);
+        let ttl = Duration::from_secs(60);
+
+        cache.put("a", cached("a", ttl));
+        cache.put("b", cached("b", ttl));
+        assert!(cache.get("a").is_some());
+        cache.put("c", cached("c", ttl));
+
+        assert!(cache.get("a").is_some());
+        assert!(cache.get("b").is_none());
+        assert!(cache.get("c").is_some());
+
+        cache.remove("a");
+        assert!(cache.get("a").is_none());
+    }
+
+    #[test]
+    fn disk_cache_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = DiskCache::new(dir.path()).unwrap();
+
+        let mut response = cached("{\"events\":[]}", Duration::from_secs(60));
+        response
+            .headers
+            .insert(header::ETAG, HeaderValue::from_static("\"abc\""));
+        cache.put("https://zsr.octane.gg/events", response.clone());
+
+        let stored = cache.get("https://zsr.octane.gg/events").unwrap();
+        assert_eq!(stored.body, response.body);
+        assert_eq!(stored.headers, response.headers);
+        assert!(!stored.is_expired());
+        assert!(cache.get("https://zsr.octane.gg/games").is_none());
+
+        cache.remove("https://zsr.octane.gg/events");
+        assert!(cache.get("https://zsr.octane.gg/events").is_none());
+    }
+
+    #[test]
+    fn lookup_and_revalidate() {
+        let cache = ResponseCache::new(
+            MemoryCache::new(10),
+            CachePolicy::new(Duration::ZERO).ttl("teams", None),
+        );
+        let rest_url = Url::parse("https://zsr.octane.gg/").unwrap();
+        let url = rest_url.join("events/abc").unwrap();
+
+        let mut headers = HeaderMap::new();
+        let Lookup::Miss { ttl, stale } = cache.lookup(&Method::GET, &url, &rest_url, &mut headers)
+        else {
+            panic!("expected a cache miss");
+        };
+        assert!(stale.is_none());
+        assert!(headers.is_empty());
+
+        let response = Response::builder()
+            .header(header::ETAG, "\"v1\"")
+            .body(Bytes::from_static(b"{}"))
+            .unwrap();
+        cache.store(&url, ttl, stale, response);
+
+        // A zero TTL expires immediately, so the response is revalidated
+        let Lookup::Miss { ttl, stale } = cache.lookup(&Method::GET, &url, &rest_url, &mut headers)
+        else {
+            panic!("expected a stale cache entry");
+        };
+        assert!(stale.is_some());
+        assert_eq!(headers[header::IF_NONE_MATCH], "\"v1\"");
+
+        let not_modified = Response::builder()
+            .status(StatusCode::NOT_MODIFIED)
+            .body(Bytes::new())
+            .unwrap();
+        let response = cache.store(&url, ttl, stale, not_modified);
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.body().as_ref(), b"{}");
+
+        let teams = rest_url.join("teams").unwrap();
+        assert!(matches!(
+            cache.lookup(&Method::GET, &teams, &rest_url, &mut HeaderMap::new()),
+            Lookup::Skip
+        ));
+    }
+
+    #[test]
+    fn fresh_hit() {
+        let cache = ResponseCache::new(MemoryCache::new(10), CachePolicy::default());
+        let rest_url = Url::parse("https://zsr.octane.gg/").unwrap();
+        let url = rest_url.join("games?page=2").unwrap();
+
+        cache.store(
+            &url,
+            Duration::from_secs(60),
+            None,
+            Response::new(Bytes::from_static(b"[]")),
+        );
+
+        assert!(matches!(
+            cache.lookup(&Method::GET, &url, &rest_url, &mut HeaderMap::new()),
+            Lookup::Hit(_)
+        ));
+    }
+}
@@ -0,0 +1,423 @@
+//! Record and replay HTTP interactions
+//!
+//! A [`RecordingClient`] wraps another client and saves every response it
+//! receives to a fixture directory. A [`ReplayClient`] serves those responses
+//! back without touching the network, which makes tests of endpoints,
+//! [`PagedIter`] and [`stream`] deterministic.
+//!
+//! Fixtures are keyed by the request URL relative to the API base URL, so
+//! fixtures recorded against a mirror can be replayed as if they came from
+//! `https://zsr.octane.gg/`.
+//!
+//! # Example
+//!
+#![cfg_attr(feature = "blocking", doc = "```rust ,no_run")]
+#![cfg_attr(not(feature = "blocking"), doc = "```rust ,ignore")]
+//! use octanezsr_api::{
+//!     api::{events::Event, Query},
+//!     cassette::{RecordingClient, ReplayClient},
+//!     types, OctaneZsrBuilder,
+//! };
+//!
+//! # fn main() -> anyhow::Result<()> {
+//! let endpoint = Event::builder().id("5f35882d53fbbb5894b43040").build()?;
+//!
+//! // Record the response once...
+//! let client = RecordingClient::new(OctaneZsrBuilder::new().build()?, "tests/fixtures")?;
+//! let _: types::Event = endpoint.query(&client)?;
+//!
+//! // ...and replay it offline.
+//! let client = ReplayClient::new("tests/fixtures");
+//! let event: types::Event = endpoint.query(&client)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`PagedIter`]: crate::api::PagedIter
+//! [`stream`]: crate::PagedEndpointExt::stream
+use std::{
+    error::Error,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::{request::Builder as RequestBuilder, Response};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use url::Url;
+
+use crate::{
+    api::{self, utils::fnv1a, ApiError},
+    ZSR_API_BASE_URL,
+};
+
+/// Errors from the [`RecordingClient`] and [`ReplayClient`]
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum CassetteError {
+    /// Error from the wrapped client
+    #[error("client error: {0}")]
+    Client(Box<dyn Error + Send + Sync>),
+    /// Error reading or writing a fixture
+    #[error("fixture I/O error: {0}")]
+    Io(#[from] io::Error),
+    /// Error (de)serializing a fixture
+    #[error("fixture format error: {0}")]
+    Format(#[from] serde_json::Error),
+    /// Error rebuilding a recorded response
+    #[error("HTTP error: {0}")]
+    Http(#[from] http::Error),
+    /// No response was recorded for a request
+    #[error("no recorded response for {0}")]
+    Missing(String),
+}
+
+impl CassetteError {
+    fn client<E>(source: E) -> Self
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        Self::Client(Box::new(source))
+    }
+}
+
+/// A recorded response, as stored in a fixture file
+#[derive(Debug, Serialize, Deserialize)]
+struct Fixture {
+    url: String,
+    status: u16,
+    #[serde(default)]
+    headers: Vec<(String, String)>,
+    body: FixtureBody,
+}
+
+/// A recorded response body
+///
+/// Bodies are stored as text so fixtures stay readable, unless they are not
+/// valid UTF-8.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+enum FixtureBody {
+    Text(String),
+    Bytes(Vec<u8>),
+}
+
+impl From<&Bytes> for FixtureBody {
+    fn from(body: &Bytes) -> Self {
+        match std::str::from_utf8(body) {
+            Ok(text) => Self::Text(text.to_string()),
+            Err(_) => Self::Bytes(body.to_vec()),
+        }
+    }
+}
+
+impl From<FixtureBody> for Bytes {
+    fn from(body: FixtureBody) -> Self {
+        match body {
+            FixtureBody::Text(text) => text.into(),
+            FixtureBody::Bytes(bytes) => bytes.into(),
+        }
+    }
+}
+
+impl Fixture {
+    fn path(dir: &Path, key: &str) -> PathBuf {
+        dir.join(format!("{:016x}.json", fnv1a(key.as_bytes())))
+    }
+
+    fn save(dir: &Path, key: &str, rsp: &Response<Bytes>) -> Result<(), CassetteError> {
+        let fixture = Fixture {
+            url: key.to_string(),
+            status: rsp.status().as_u16(),
+            headers: rsp
+                .headers()
+                .iter()
+                .filter_map(|(name, value)| {
+                    Some((name.to_string(), value.to_str().ok()?.to_string()))
+                })
+                .collect(),
+            body: rsp.body().into(),
+        };
+
+        fs::write(Self::path(dir, key), serde_json::to_vec_pretty(&fixture)?)?;
+        Ok(())
+    }
+
+    fn load(dir: &Path, key: &str) -> Result<Response<Bytes>, CassetteError> {
+        let data = match fs::read(Self::path(dir, key)) {
+            Ok(data) => data,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return Err(CassetteError::Missing(key.to_string()))
+            }
+            Err(err) => return Err(err.into()),
+        };
+        let fixture: Fixture = serde_json::from_slice(&data)?;
+        if fixture.url != key {
+            return Err(CassetteError::Missing(key.to_string()));
+        }
+
+        let mut rsp = Response::builder().status(fixture.status);
+        for (name, value) in &fixture.headers {
+            rsp = rsp.header(name, value);
+        }
+        Ok(rsp.body(fixture.body.into())?)
+    }
+}
+
+/// Key for a request URL, relative to the API base URL
+fn fixture_key(base: &Url, uri: Option<&http::Uri>) -> String {
+    let url = uri.map(ToString::to_string).unwrap_or_default();
+    url.strip_prefix(base.as_str()).unwrap_or(&url).to_string()
+}
+
+/// A client that records the responses of a wrapped client to a fixture
+/// directory
+#[derive(Debug, Clone)]
+pub struct RecordingClient<C> {
+    inner: C,
+    dir: PathBuf,
+}
+
+impl<C> RecordingClient<C> {
+    /// Create a new [`RecordingClient`] recording to `dir`, creating the
+    /// directory if needed
+    pub fn new<P>(inner: C, dir: P) -> io::Result<Self>
+    where
+        P: Into<PathBuf>,
+    {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self { inner, dir })
+    }
+
+    /// Consumes the [`RecordingClient`] returning the wrapped client.
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+}
+
+impl<C> RecordingClient<C>
+where
+    C: api::RestClient,
+{
+    fn key(&self, request: &RequestBuilder) -> Result<String, ApiError<CassetteError>> {
+        let base = self
+            .inner
+            .rest_endpoint("")
+            .map_err(|err| err.map_client(CassetteError::client))?;
+        Ok(fixture_key(&base, request.uri_ref()))
+    }
+}
+
+impl<C> api::RestClient for RecordingClient<C>
+where
+    C: api::RestClient,
+{
+    type Error = CassetteError;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        self.inner
+            .rest_endpoint(endpoint)
+            .map_err(|err| err.map_client(CassetteError::client))
+    }
+}
+
+impl<C> api::Client for RecordingClient<C>
+where
+    C: api::Client,
+{
+    fn rest(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        let key = self.key(&request)?;
+        let rsp = self
+            .inner
+            .rest(request, body)
+            .map_err(|err| err.map_client(CassetteError::client))?;
+
+        Fixture::save(&self.dir, &key, &rsp).map_err(ApiError::client)?;
+        Ok(rsp)
+    }
+}
+
+#[async_trait]
+impl<C> api::AsyncClient for RecordingClient<C>
+where
+    C: api::AsyncClient + Sync,
+{
+    async fn rest_async(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        let key = self.key(&request)?;
+        let rsp = self
+            .inner
+            .rest_async(request, body)
+            .await
+            .map_err(|err| err.map_client(CassetteError::client))?;
+
+        Fixture::save(&self.dir, &key, &rsp).map_err(ApiError::client)?;
+        Ok(rsp)
+    }
+}
+
+/// A client that replays responses recorded by a [`RecordingClient`]
+#[derive(Debug, Clone)]
+pub struct ReplayClient {
+    rest_url: Url,
+    dir: PathBuf,
+}
+
+impl ReplayClient {
+    /// Create a new [`ReplayClient`] replaying fixtures from `dir`
+    pub fn new<P>(dir: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self {
+            rest_url: Url::parse(ZSR_API_BASE_URL).expect("invalid default base URL"),
+            dir: dir.into(),
+        }
+    }
+
+    fn replay(&self, request: &RequestBuilder) -> Result<Response<Bytes>, ApiError<CassetteError>> {
+        let key = fixture_key(&self.rest_url, request.uri_ref());
+        Fixture::load(&self.dir, &key).map_err(ApiError::client)
+    }
+}
+
+impl api::RestClient for ReplayClient {
+    type Error = CassetteError;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        Ok(self.rest_url.join(endpoint.trim_start_matches('/'))?)
+    }
+}
+
+impl api::Client for ReplayClient {
+    fn rest(
+        &self,
+        request: RequestBuilder,
+        _body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        self.replay(&request)
+    }
+}
+
+#[async_trait]
+impl api::AsyncClient for ReplayClient {
+    async fn rest_async(
+        &self,
+        request: RequestBuilder,
+        _body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        self.replay(&request)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::convert::Infallible;
+
+    use futures::TryStreamExt;
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::{
+        api::{events::ListEvents, teams::Team, Query},
+        PagedEndpointExt,
+    };
+
+    /// Serves pages of events from a mirror with a path prefix
+    struct Stub;
+
+    impl api::RestClient for Stub {
+        type Error = Infallible;
+
+        fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+            let base = Url::parse("http://mirror.local/zsr/").unwrap();
+            Ok(base.join(endpoint.trim_start_matches('/'))?)
+        }
+    }
+
+    impl api::Client for Stub {
+        fn rest(
+            &self,
+            request: RequestBuilder,
+            _body: Vec<u8>,
+        ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+            let body = match request.uri_ref().unwrap().to_string().as_str() {
+                "http://mirror.local/zsr/teams/abc" => r#"{"name":"Team"}"#,
+                "http://mirror.local/zsr/events" => {
+                    r#"{"events":[{"name":"a"},{"name":"b"}],"page":1,"perPage":2,"pageSize":2}"#
+                }
+                "http://mirror.local/zsr/events?page=2" => {
+                    r#"{"events":[{"name":"c"}],"page":2,"perPage":2,"pageSize":1}"#
+                }
+                "http://mirror.local/zsr/events?page=3" => {
+                    r#"{"events":[],"page":3,"perPage":2,"pageSize":0}"#
+                }
+                _ => return Ok(Response::builder().status(404).body("{}".into()).unwrap()),
+            };
+            Ok(Response::builder().status(200).body(body.into()).unwrap())
+        }
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Eq)]
+    struct Named {
+        name: String,
+    }
+
+    #[tokio::test]
+    async fn record_and_replay() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let recorder = RecordingClient::new(Stub, dir.path()).unwrap();
+        let endpoint = Team::builder().id("abc").build().unwrap();
+        let team: Named = endpoint.query(&recorder).unwrap();
+        let events = ListEvents::builder().build().unwrap();
+        let recorded: Vec<Named> = events.iter(&recorder).collect::<Result<_, _>>().unwrap();
+
+        let replay = ReplayClient::new(dir.path());
+        assert_eq!(team, endpoint.query(&replay).unwrap());
+
+        let replayed: Vec<Named> = events.stream(&replay).try_collect().await.unwrap();
+        assert_eq!(replayed, recorded);
+        assert_eq!(replayed.len(), 3);
+    }
+
+    #[test]
+    fn missing_fixture() {
+        let dir = tempfile::tempdir().unwrap();
+        let replay = ReplayClient::new(dir.path());
+        let endpoint = Team::builder().id("abc").build().unwrap();
+
+        let err = Query::<Named, _>::query(&endpoint, &replay).unwrap_err();
+        assert!(matches!(err, ApiError::Client(CassetteError::Missing(url)) if url == "teams/abc"));
+    }
+
+    #[test]
+    fn bodies_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+
+        for (key, body) in [
+            ("text", &b"{\"name\":\"Team\"}"[..]),
+            ("binary", b"\xff\x00\xfe"),
+        ] {
+            let rsp = Response::builder()
+                .status(200)
+                .body(Bytes::from_static(body))
+                .unwrap();
+            Fixture::save(dir.path(), key, &rsp).unwrap();
+            assert_eq!(Fixture::load(dir.path(), key).unwrap().body(), body);
+        }
+
+        let text = fs::read_to_string(Fixture::path(dir.path(), "text")).unwrap();
+        assert!(text.contains(r#""body": "{\"name\":\"Team\"}""#));
+    }
+}
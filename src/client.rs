@@ -1,33 +1,48 @@
+#[cfg(feature = "async")]
+use std::sync::Arc;
+use std::time::Duration;
+
+#[cfg(feature = "async")]
 use async_trait::async_trait;
 use bytes::Bytes;
-use http::{request::Builder as RequestBuilder, response::Builder as ResponseBuilder, Response};
+use http::{
+    request::Builder as RequestBuilder, response::Builder as ResponseBuilder, HeaderMap, Response,
+};
 use log::debug;
-use reqwest::{blocking::Client as HttpClient, Client as AsyncHttpClient};
+#[cfg(feature = "blocking")]
+use reqwest::blocking::Client as HttpClient;
+#[cfg(feature = "async")]
+use reqwest::Client as AsyncHttpClient;
+use reqwest::Proxy;
+#[cfg(feature = "async")]
+use tokio::runtime::{Builder as RuntimeBuilder, Runtime};
 use url::Url;
 
 use crate::{
     api,
+    cache::{Cache, CachePolicy, Lookup, ResponseCache},
     error::{OctaneZsrResult, RestError},
+    rate_limit::RateLimiter,
+    retry::{Attempts, RetryPolicy},
+    ZSR_API_BASE_URL,
 };
 
-const ZSR_API_BASE_URL: &str = "https://zsr.octane.gg/";
-
 /// A client for communicating with the Octane ZSR API
+#[cfg(feature = "blocking")]
 #[derive(Clone, Debug)]
 pub struct OctaneZsrClient {
     client: HttpClient,
     rest_url: Url,
+    retry: Option<RetryPolicy>,
+    rate_limiter: Option<RateLimiter>,
+    cache: Option<ResponseCache>,
 }
 
+#[cfg(feature = "blocking")]
 impl OctaneZsrClient {
     /// Create a new Octane ZSR client.
     pub fn new() -> OctaneZsrResult<Self> {
-        let rest_url = Url::parse(ZSR_API_BASE_URL)?;
-
-        Ok(Self {
-            client: HttpClient::new(),
-            rest_url,
-        })
+        OctaneZsrBuilder::new().build()
     }
 
     /// Create a new Octane ZSR API builder.
@@ -36,6 +51,7 @@ impl OctaneZsrClient {
     }
 }
 
+#[cfg(feature = "blocking")]
 impl api::RestClient for OctaneZsrClient {
     type Error = RestError;
 
@@ -44,6 +60,49 @@ impl api::RestClient for OctaneZsrClient {
     }
 }
 
+#[cfg(feature = "blocking")]
+impl OctaneZsrClient {
+    fn send(&self, request: reqwest::blocking::Request) -> Result<Response<Bytes>, RestError> {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.wait();
+        }
+
+        let rsp = self.client.execute(request)?;
+
+        let http_rsp = build_http_response(rsp.status(), rsp.version(), rsp.headers());
+
+        Ok(http_rsp.body(rsp.bytes()?)?)
+    }
+
+    fn send_with_retry(
+        &self,
+        mut request: reqwest::blocking::Request,
+    ) -> Result<Response<Bytes>, RestError> {
+        let mut attempts = Attempts::new(self.retry.as_ref());
+        loop {
+            let retry_request = if attempts.may_retry() {
+                request.try_clone()
+            } else {
+                None
+            };
+            let Some(retry_request) = retry_request else {
+                return self.send(request);
+            };
+
+            let result = self.send(request);
+            match attempts.next_delay(&result) {
+                Some(delay) => {
+                    debug!("retrying {} in {:?}", retry_request.url(), delay);
+                    std::thread::sleep(delay);
+                    request = retry_request;
+                }
+                None => return result,
+            }
+        }
+    }
+}
+
+#[cfg(feature = "blocking")]
 impl api::Client for OctaneZsrClient {
     fn rest(
         &self,
@@ -51,15 +110,23 @@ impl api::Client for OctaneZsrClient {
         body: Vec<u8>,
     ) -> Result<Response<Bytes>, api::ApiError<Self::Error>> {
         let call = || -> Result<_, RestError> {
-            let request: reqwest::blocking::Request = request.body(body)?.try_into()?;
+            let mut request: reqwest::blocking::Request = request.body(body)?.try_into()?;
 
             debug!("{}", &request.url());
 
-            let rsp = self.client.execute(request)?;
-
-            let http_rsp = build_http_response(rsp.status(), rsp.version(), rsp.headers());
+            let Some(cache) = &self.cache else {
+                return self.send_with_retry(request);
+            };
 
-            Ok(http_rsp.body(rsp.bytes()?)?)
+            let (method, url) = (request.method().clone(), request.url().clone());
+            match cache.lookup(&method, &url, &self.rest_url, request.headers_mut()) {
+                Lookup::Skip => self.send_with_retry(request),
+                Lookup::Hit(rsp) => Ok(rsp),
+                Lookup::Miss { ttl, stale } => {
+                    let rsp = self.send_with_retry(request)?;
+                    Ok(cache.store(&url, ttl, stale, rsp))
+                }
+            }
         };
 
         call().map_err(api::ApiError::client)
@@ -67,20 +134,21 @@ impl api::Client for OctaneZsrClient {
 }
 
 /// An asynchronous client for communicating with the Octane ZSR API
+#[cfg(feature = "async")]
+#[derive(Clone, Debug)]
 pub struct OctaneZsrClientAsync {
     client: AsyncHttpClient,
     rest_url: Url,
+    retry: Option<RetryPolicy>,
+    rate_limiter: Option<RateLimiter>,
+    cache: Option<ResponseCache>,
 }
 
+#[cfg(feature = "async")]
 impl OctaneZsrClientAsync {
     /// Create a new asynchronous Octane ZSR client
     pub fn new() -> OctaneZsrResult<Self> {
-        let rest_url = Url::parse(ZSR_API_BASE_URL)?;
-
-        Ok(Self {
-            client: AsyncHttpClient::new(),
-            rest_url,
-        })
+        OctaneZsrBuilder::new().build_async()
     }
 
     /// Create a new Octane ZSR API builder.
@@ -89,6 +157,7 @@ impl OctaneZsrClientAsync {
     }
 }
 
+#[cfg(feature = "async")]
 impl api::RestClient for OctaneZsrClientAsync {
     type Error = RestError;
 
@@ -97,6 +166,49 @@ impl api::RestClient for OctaneZsrClientAsync {
     }
 }
 
+#[cfg(feature = "async")]
+impl OctaneZsrClientAsync {
+    async fn send(&self, request: reqwest::Request) -> Result<Response<Bytes>, RestError> {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.wait_async().await;
+        }
+
+        let rsp = self.client.execute(request).await?;
+
+        let http_rsp = build_http_response(rsp.status(), rsp.version(), rsp.headers());
+
+        Ok(http_rsp.body(rsp.bytes().await?)?)
+    }
+
+    async fn send_with_retry(
+        &self,
+        mut request: reqwest::Request,
+    ) -> Result<Response<Bytes>, RestError> {
+        let mut attempts = Attempts::new(self.retry.as_ref());
+        loop {
+            let retry_request = if attempts.may_retry() {
+                request.try_clone()
+            } else {
+                None
+            };
+            let Some(retry_request) = retry_request else {
+                return self.send(request).await;
+            };
+
+            let result = self.send(request).await;
+            match attempts.next_delay(&result) {
+                Some(delay) => {
+                    debug!("retrying {} in {:?}", retry_request.url(), delay);
+                    tokio::time::sleep(delay).await;
+                    request = retry_request;
+                }
+                None => return result,
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
 #[async_trait]
 impl api::AsyncClient for OctaneZsrClientAsync {
     async fn rest_async(
@@ -105,24 +217,100 @@ impl api::AsyncClient for OctaneZsrClientAsync {
         body: Vec<u8>,
     ) -> Result<Response<Bytes>, api::ApiError<Self::Error>> {
         let call = || async {
-            let request: reqwest::Request = request.body(body)?.try_into()?;
+            let mut request: reqwest::Request = request.body(body)?.try_into()?;
 
             debug!("{}", &request.url());
 
-            let rsp = self.client.execute(request).await?;
+            let Some(cache) = &self.cache else {
+                return self.send_with_retry(request).await;
+            };
 
-            let http_rsp = build_http_response(rsp.status(), rsp.version(), rsp.headers());
-
-            Ok(http_rsp.body(rsp.bytes().await?)?)
+            let (method, url) = (request.method().clone(), request.url().clone());
+            match cache.lookup(&method, &url, &self.rest_url, request.headers_mut()) {
+                Lookup::Skip => self.send_with_retry(request).await,
+                Lookup::Hit(rsp) => Ok(rsp),
+                Lookup::Miss { ttl, stale } => {
+                    let rsp = self.send_with_retry(request).await?;
+                    Ok(cache.store(&url, ttl, stale, rsp))
+                }
+            }
         };
 
         call().await.map_err(api::ApiError::client)
     }
 }
 
+/// A blocking client for communicating with the Octane ZSR API, backed by an
+/// [`OctaneZsrClientAsync`]
+///
+/// Requests are run to completion on an internal single-threaded Tokio
+/// runtime, so this client works with [`Query`](api::Query) and
+/// [`PagedIter`](api::PagedIter) without reqwest's blocking client. The
+/// runtime owns the connections of the wrapped client, so the HTTP client is
+/// never shared with other runtimes. Like reqwest's blocking client, it must
+/// not be used or dropped from within an asynchronous context.
+#[cfg(feature = "async")]
+#[derive(Clone, Debug)]
+pub struct OctaneZsrClientBlocking {
+    client: OctaneZsrClientAsync,
+    runtime: Arc<Runtime>,
+}
+
+#[cfg(feature = "async")]
+impl OctaneZsrClientBlocking {
+    /// Create a new blocking Octane ZSR client
+    pub fn new() -> OctaneZsrResult<Self> {
+        OctaneZsrBuilder::new().build_blocking()
+    }
+
+    /// Create a new Octane ZSR API builder.
+    pub fn builder() -> OctaneZsrBuilder {
+        OctaneZsrBuilder::new()
+    }
+}
+
+#[cfg(feature = "async")]
+impl api::RestClient for OctaneZsrClientBlocking {
+    type Error = RestError;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, api::ApiError<Self::Error>> {
+        self.client.rest_endpoint(endpoint)
+    }
+}
+
+#[cfg(feature = "async")]
+impl api::Client for OctaneZsrClientBlocking {
+    fn rest(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, api::ApiError<Self::Error>> {
+        use api::AsyncClient;
+
+        self.runtime.block_on(self.client.rest_async(request, body))
+    }
+}
+
 /// Octane ZSR API client builder
+///
+/// By default the clients talk to `https://zsr.octane.gg/` using reqwest's
+/// default settings.
 #[derive(Debug, Default)]
-pub struct OctaneZsrBuilder;
+pub struct OctaneZsrBuilder {
+    base_url: Option<String>,
+    user_agent: Option<String>,
+    default_headers: HeaderMap,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    proxies: Vec<Proxy>,
+    #[cfg(feature = "blocking")]
+    http_client: Option<HttpClient>,
+    #[cfg(feature = "async")]
+    async_http_client: Option<AsyncHttpClient>,
+    retry: Option<RetryPolicy>,
+    rate_limiter: Option<RateLimiter>,
+    cache: Option<ResponseCache>,
+}
 
 impl OctaneZsrBuilder {
     /// Create a new Octane ZSR API builder.
@@ -130,17 +318,260 @@ impl OctaneZsrBuilder {
         OctaneZsrBuilder::default()
     }
 
+    /// Base URL of the API. Example: `"http://localhost:8080/"`
+    ///
+    /// Endpoint paths are resolved relative to this URL, so a base URL with a
+    /// path prefix such as `"https://mirror.example.com/zsr"` works as
+    /// expected.
+    pub fn base_url<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.base_url = Some(value.into());
+        self
+    }
+
+    /// Value of the `User-Agent` header sent with every request.
+    pub fn user_agent<T>(mut self, value: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.user_agent = Some(value.into());
+        self
+    }
+
+    /// Headers sent with every request.
+    pub fn default_headers(mut self, headers: HeaderMap) -> Self {
+        self.default_headers = headers;
+        self
+    }
+
+    /// Timeout for establishing a connection.
+    pub fn connect_timeout<T>(mut self, value: T) -> Self
+    where
+        T: Into<Option<Duration>>,
+    {
+        self.connect_timeout = value.into();
+        self
+    }
+
+    /// Timeout for a complete request, from connecting until the response
+    /// body has been read.
+    pub fn timeout<T>(mut self, value: T) -> Self
+    where
+        T: Into<Option<Duration>>,
+    {
+        self.timeout = value.into();
+        self
+    }
+
+    /// Add a proxy to use for requests.
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Use a pre-built blocking HTTP client.
+    ///
+    /// The user agent, default headers, timeouts and proxies configured on
+    /// this builder are ignored by [`build`](Self::build) when a client is
+    /// supplied.
+    #[cfg(feature = "blocking")]
+    pub fn http_client(mut self, client: HttpClient) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /// Use a pre-built asynchronous HTTP client.
+    ///
+    /// The user agent, default headers, timeouts and proxies configured on
+    /// this builder are ignored by [`build_async`](Self::build_async) when a
+    /// client is supplied.
+    #[cfg(feature = "async")]
+    pub fn async_http_client(mut self, client: AsyncHttpClient) -> Self {
+        self.async_http_client = Some(client);
+        self
+    }
+
+    /// Retry failed requests according to a [`RetryPolicy`].
+    ///
+    /// Requests are not retried by default.
+    pub fn retry<T>(mut self, policy: T) -> Self
+    where
+        T: Into<Option<RetryPolicy>>,
+    {
+        self.retry = policy.into();
+        self
+    }
+
+    /// Limit requests to `requests_per_second` on average, allowing bursts of
+    /// up to `burst` requests.
+    ///
+    /// All clients built from this builder share the same limit.
+    pub fn rate_limit(self, requests_per_second: f64, burst: u32) -> Self {
+        self.rate_limiter(RateLimiter::new(requests_per_second, burst))
+    }
+
+    /// Limit requests with an existing [`RateLimiter`], e.g. one shared with
+    /// clients built from other builders.
+    pub fn rate_limiter<T>(mut self, limiter: T) -> Self
+    where
+        T: Into<Option<RateLimiter>>,
+    {
+        self.rate_limiter = limiter.into();
+        self
+    }
+
+    /// Cache responses in `backend`, for as long as `policy` allows.
+    ///
+    /// All clients built from this builder share the same cache.
+    pub fn cache<C>(mut self, backend: C, policy: CachePolicy) -> Self
+    where
+        C: Cache + 'static,
+    {
+        self.cache = Some(ResponseCache::new(backend, policy));
+        self
+    }
+
     /// Build a blocking Octane ZSR client.
+    #[cfg(feature = "blocking")]
     pub fn build(&self) -> OctaneZsrResult<OctaneZsrClient> {
-        OctaneZsrClient::new()
+        let client = match &self.http_client {
+            Some(client) => client.clone(),
+            None => self.build_http_client(HttpClient::builder())?,
+        };
+
+        Ok(OctaneZsrClient {
+            client,
+            rest_url: self.rest_url()?,
+            retry: self.retry.clone(),
+            rate_limiter: self.rate_limiter.clone(),
+            cache: self.cache.clone(),
+        })
     }
 
     /// Build an asynchronous Octane ZSR client.
+    #[cfg(feature = "async")]
     pub fn build_async(&self) -> OctaneZsrResult<OctaneZsrClientAsync> {
-        OctaneZsrClientAsync::new()
+        let client = match &self.async_http_client {
+            Some(client) => client.clone(),
+            None => self.build_http_client(AsyncHttpClient::builder())?,
+        };
+
+        self.async_client(client)
+    }
+
+    /// Build a blocking Octane ZSR client backed by an asynchronous client.
+    ///
+    /// Unlike [`build`](Self::build), this does not need reqwest's blocking
+    /// client. The client supplied to
+    /// [`async_http_client`](Self::async_http_client) is not used: its
+    /// connections could be shared with another runtime, so a new HTTP client
+    /// is always built from the options of this builder.
+    #[cfg(feature = "async")]
+    pub fn build_blocking(&self) -> OctaneZsrResult<OctaneZsrClientBlocking> {
+        let client = self.build_http_client(AsyncHttpClient::builder())?;
+        let runtime = RuntimeBuilder::new_current_thread().enable_all().build()?;
+
+        Ok(OctaneZsrClientBlocking {
+            client: self.async_client(client)?,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    #[cfg(feature = "async")]
+    fn async_client(&self, client: AsyncHttpClient) -> OctaneZsrResult<OctaneZsrClientAsync> {
+        Ok(OctaneZsrClientAsync {
+            client,
+            rest_url: self.rest_url()?,
+            retry: self.retry.clone(),
+            rate_limiter: self.rate_limiter.clone(),
+            cache: self.cache.clone(),
+        })
     }
+
+    /// Apply the HTTP options of this builder to a reqwest client builder and
+    /// build the client.
+    fn build_http_client<B>(&self, builder: B) -> reqwest::Result<B::Client>
+    where
+        B: HttpClientBuilder,
+    {
+        let mut builder = builder.default_headers(self.default_headers.clone());
+        if let Some(user_agent) = &self.user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+        if let Some(connect_timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        for proxy in &self.proxies {
+            builder = builder.proxy(proxy.clone());
+        }
+        builder.build()
+    }
+
+    fn rest_url(&self) -> OctaneZsrResult<Url> {
+        let mut rest_url = Url::parse(self.base_url.as_deref().unwrap_or(ZSR_API_BASE_URL))?;
+        // `Url::join` replaces the last path segment unless it ends in a slash
+        if !rest_url.path().ends_with('/') {
+            let path = format!("{}/", rest_url.path());
+            rest_url.set_path(&path);
+        }
+        Ok(rest_url)
+    }
+}
+
+/// The options shared by reqwest's blocking and asynchronous client builders
+trait HttpClientBuilder: Sized {
+    type Client;
+
+    fn default_headers(self, headers: HeaderMap) -> Self;
+    fn user_agent(self, user_agent: &str) -> Self;
+    fn connect_timeout(self, timeout: Duration) -> Self;
+    fn timeout(self, timeout: Duration) -> Self;
+    fn proxy(self, proxy: Proxy) -> Self;
+    fn build(self) -> reqwest::Result<Self::Client>;
 }
 
+macro_rules! impl_http_client_builder {
+    ($builder:ty, $client:ty) => {
+        impl HttpClientBuilder for $builder {
+            type Client = $client;
+
+            fn default_headers(self, headers: HeaderMap) -> Self {
+                self.default_headers(headers)
+            }
+
+            fn user_agent(self, user_agent: &str) -> Self {
+                self.user_agent(user_agent)
+            }
+
+            fn connect_timeout(self, timeout: Duration) -> Self {
+                self.connect_timeout(timeout)
+            }
+
+            fn timeout(self, timeout: Duration) -> Self {
+                self.timeout(timeout)
+            }
+
+            fn proxy(self, proxy: Proxy) -> Self {
+                self.proxy(proxy)
+            }
+
+            fn build(self) -> reqwest::Result<Self::Client> {
+                self.build()
+            }
+        }
+    };
+}
+
+#[cfg(feature = "blocking")]
+impl_http_client_builder!(reqwest::blocking::ClientBuilder, HttpClient);
+#[cfg(feature = "async")]
+impl_http_client_builder!(reqwest::ClientBuilder, AsyncHttpClient);
+
 fn build_http_response(
     status: http::StatusCode,
     version: http::Version,
@@ -154,3 +585,356 @@ fn build_http_response(
     }
     builder
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::api::RestClient;
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn default_base_url() {
+        let client = OctaneZsrBuilder::new().build().unwrap();
+        let url = client.rest_endpoint("/events").unwrap();
+
+        assert_eq!(url.as_str(), "https://zsr.octane.gg/events");
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn custom_base_url() {
+        let client = OctaneZsrBuilder::new()
+            .base_url("http://localhost:8080/zsr")
+            .build_async()
+            .unwrap();
+        let url = client.rest_endpoint("/events").unwrap();
+
+        assert_eq!(url.as_str(), "http://localhost:8080/zsr/events");
+    }
+
+    /// A response of the test server
+    struct Reply {
+        status: u16,
+        headers: Vec<(&'static str, &'static str)>,
+        body: String,
+    }
+
+    impl Reply {
+        fn json(body: serde_json::Value) -> Self {
+            Self {
+                status: 200,
+                headers: vec![("Content-Type", "application/json")],
+                body: body.to_string(),
+            }
+        }
+
+        fn status(status: u16) -> Self {
+            Self {
+                status,
+                headers: Vec::new(),
+                body: String::new(),
+            }
+        }
+
+        fn header(mut self, name: &'static str, value: &'static str) -> Self {
+            self.headers.push((name, value));
+            self
+        }
+    }
+
+    /// A request received by the test server
+    struct Received {
+        /// Request line. Example: `"GET /events HTTP/1.1"`
+        line: String,
+        headers: HeaderMap,
+    }
+
+    /// Answer one request with each of `replies`, returning the base URL of
+    /// the server and the requests it received
+    fn serve(replies: Vec<Reply>) -> (String, std::thread::JoinHandle<Vec<Received>>) {
+        use std::{
+            io::{BufRead, BufReader, Write},
+            net::TcpListener,
+        };
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}/", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            let mut requests = Vec::new();
+            for reply in replies {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let mut request = Received {
+                    line: line.trim_end().to_owned(),
+                    headers: HeaderMap::new(),
+                };
+                loop {
+                    line.clear();
+                    reader.read_line(&mut line).unwrap();
+                    let Some((name, value)) = line.trim_end().split_once(':') else {
+                        break;
+                    };
+                    request.headers.append(
+                        http::HeaderName::try_from(name).unwrap(),
+                        value.trim().parse().unwrap(),
+                    );
+                }
+                requests.push(request);
+
+                let status = http::StatusCode::from_u16(reply.status).unwrap();
+                write!(stream, "HTTP/1.1 {}\r\n", status).unwrap();
+                for (name, value) in reply.headers {
+                    write!(stream, "{}: {}\r\n", name, value).unwrap();
+                }
+                write!(
+                    stream,
+                    "Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    reply.body.len(),
+                    reply.body
+                )
+                .unwrap();
+            }
+            requests
+        });
+
+        (base_url, server)
+    }
+
+    fn request_lines(server: std::thread::JoinHandle<Vec<Received>>) -> Vec<String> {
+        server
+            .join()
+            .unwrap()
+            .into_iter()
+            .map(|request| request.line)
+            .collect()
+    }
+
+    /// Check that a client built by `build` retries a `503` response after the
+    /// delay asked for by its `Retry-After` header
+    fn retries_unavailable<C, F>(build: F)
+    where
+        C: api::Client,
+        F: FnOnce(OctaneZsrBuilder) -> OctaneZsrResult<C>,
+    {
+        use std::time::Instant;
+
+        use serde_json::{json, Value};
+
+        use crate::api::{events::Event, Query};
+
+        let (base_url, server) = serve(vec![
+            Reply::status(503).header("Retry-After", "1"),
+            Reply::json(json!({"name": "a"})),
+        ]);
+        let builder = OctaneZsrBuilder::new().base_url(base_url).retry(
+            RetryPolicy::new()
+                .initial_backoff(Duration::ZERO)
+                .jitter(false),
+        );
+        let client = build(builder).unwrap();
+
+        let start = Instant::now();
+        let endpoint = Event::builder().id("abc").build().unwrap();
+        let event: Value = endpoint.query(&client).unwrap();
+
+        assert_eq!(event["name"], "a");
+        assert!(start.elapsed() >= Duration::from_secs(1));
+        assert_eq!(
+            request_lines(server),
+            ["GET /events/abc HTTP/1.1", "GET /events/abc HTTP/1.1"]
+        );
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn blocking_client_retries() {
+        retries_unavailable(|builder| builder.build());
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn async_client_retries() {
+        retries_unavailable(|builder| builder.build_blocking());
+    }
+
+    /// Check that a client built by `build` waits for its rate limiter before
+    /// every request
+    fn rate_limited<C, F>(build: F)
+    where
+        C: api::Client,
+        F: FnOnce(OctaneZsrBuilder) -> OctaneZsrResult<C>,
+    {
+        use std::time::Instant;
+
+        use serde_json::{json, Value};
+
+        use crate::api::{events::Event, Query};
+
+        let (base_url, server) = serve(vec![
+            Reply::json(json!({})),
+            Reply::json(json!({})),
+            Reply::json(json!({})),
+        ]);
+        // A single token, refilled every 100ms
+        let limiter = RateLimiter::new(10.0, 1);
+        let builder = OctaneZsrBuilder::new()
+            .base_url(base_url)
+            .rate_limiter(limiter.clone());
+        let client = build(builder).unwrap();
+
+        let start = Instant::now();
+        let endpoint = Event::builder().id("abc").build().unwrap();
+        for _ in 0..3 {
+            let _: Value = endpoint.query(&client).unwrap();
+        }
+
+        assert!(start.elapsed() >= Duration::from_millis(190));
+        let stats = limiter.stats();
+        assert_eq!((stats.requests, stats.throttled), (3, 2));
+        assert_eq!(request_lines(server).len(), 3);
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn blocking_client_rate_limited() {
+        rate_limited(|builder| builder.build());
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn async_client_rate_limited() {
+        rate_limited(|builder| builder.build_blocking());
+    }
+
+    /// Check that a client built by `build` answers repeated requests from its
+    /// cache, and revalidates expired responses with their `ETag`
+    fn cached<C, F>(build: F)
+    where
+        C: api::Client,
+        F: FnOnce(OctaneZsrBuilder) -> OctaneZsrResult<C>,
+    {
+        use serde_json::{json, Value};
+
+        use crate::{
+            api::{events::Event, games::Game, Query},
+            cache::MemoryCache,
+        };
+
+        let (base_url, server) = serve(vec![
+            Reply::json(json!({"name": "event"})),
+            Reply::json(json!({"name": "game"})).header("ETag", "\"v1\""),
+            Reply::status(304).header("ETag", "\"v1\""),
+        ]);
+        // Games expire immediately, so they are revalidated on every request
+        let policy = CachePolicy::new(Duration::from_secs(60)).ttl("games", Duration::ZERO);
+        let builder = OctaneZsrBuilder::new()
+            .base_url(base_url)
+            .cache(MemoryCache::new(10), policy);
+        let client = build(builder).unwrap();
+
+        let event = Event::builder().id("abc").build().unwrap();
+        let game = Game::builder().id("abc").build().unwrap();
+        for _ in 0..2 {
+            let value: Value = event.query(&client).unwrap();
+            assert_eq!(value["name"], "event");
+            let value: Value = game.query(&client).unwrap();
+            assert_eq!(value["name"], "game");
+        }
+
+        let requests = server.join().unwrap();
+        let lines: Vec<_> = requests
+            .iter()
+            .map(|request| request.line.as_str())
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                "GET /events/abc HTTP/1.1",
+                "GET /games/abc HTTP/1.1",
+                "GET /games/abc HTTP/1.1"
+            ]
+        );
+        assert!(requests[1]
+            .headers
+            .get(http::header::IF_NONE_MATCH)
+            .is_none());
+        assert_eq!(requests[2].headers[http::header::IF_NONE_MATCH], "\"v1\"");
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn blocking_client_cached() {
+        cached(|builder| builder.build());
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn async_client_cached() {
+        cached(|builder| builder.build_blocking());
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn blocking_client_pages() {
+        use serde_json::{json, Value};
+
+        use crate::api::{events::ListEvents, PagedEndpointExt};
+
+        let (base_url, server) = serve(vec![
+            Reply::json(
+                json!({"events": [{"name": "a"}, {"name": "b"}], "page": 1, "perPage": 2, "pageSize": 2}),
+            ),
+            Reply::json(json!({"events": [{"name": "c"}], "page": 2, "perPage": 2, "pageSize": 1})),
+        ]);
+        let client = OctaneZsrBuilder::new()
+            .base_url(base_url)
+            .build_blocking()
+            .unwrap();
+
+        let endpoint = ListEvents::builder().build().unwrap();
+        let events: Vec<Value> = endpoint.iter(&client).collect::<Result<_, _>>().unwrap();
+
+        assert_eq!(events.len(), 3);
+        assert_eq!(
+            request_lines(server),
+            ["GET /events HTTP/1.1", "GET /events?page=2 HTTP/1.1"]
+        );
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn blocking_client_builds_own_http_client() {
+        use serde_json::{json, Value};
+
+        use crate::api::{events::Event, Query};
+
+        let (base_url, server) = serve(vec![Reply::json(json!({"name": "a"}))]);
+        // Requests through this client would time out immediately
+        let async_http_client = AsyncHttpClient::builder()
+            .timeout(Duration::from_nanos(1))
+            .build()
+            .unwrap();
+        let client = OctaneZsrBuilder::new()
+            .base_url(base_url)
+            .async_http_client(async_http_client)
+            .build_blocking()
+            .unwrap();
+
+        let endpoint = Event::builder().id("abc").build().unwrap();
+        let event: Value = endpoint.query(&client).unwrap();
+
+        assert_eq!(event["name"], "a");
+        assert_eq!(request_lines(server), ["GET /events/abc HTTP/1.1"]);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn invalid_base_url() {
+        let err = OctaneZsrBuilder::new().base_url("not a url").build_async();
+
+        assert!(matches!(err, Err(crate::error::OctaneZsrError::Parse(_))));
+    }
+}
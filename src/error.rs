@@ -16,6 +16,14 @@ pub enum OctaneZsrError {
     /// Error parsing URL
     #[error("url parse error: {0}")]
     Parse(#[from] url::ParseError),
+    /// Error building the HTTP client
+    #[cfg(any(feature = "blocking", feature = "async"))]
+    #[error("HTTP client error: {0}")]
+    Client(#[from] reqwest::Error),
+    /// Error starting the runtime of a blocking client
+    #[cfg(feature = "async")]
+    #[error("runtime error: {0}")]
+    Runtime(#[from] std::io::Error),
 }
 
 /// Errors communicating with the REST endpoint.
@@ -23,6 +31,7 @@ pub enum OctaneZsrError {
 #[non_exhaustive]
 pub enum RestError {
     /// Reqwest client error
+    #[cfg(any(feature = "blocking", feature = "async"))]
     #[error("Communication error: {0}")]
     Communication(#[from] reqwest::Error),
     /// HTTP protocol error
@@ -12,11 +12,44 @@
 //! This crate implements a warpper for the Octane.gg ZSR API
 //!
 //! Endpoints are available in the [api](src/api.rs) module.
+//!
+//! # Features
+//!
+//! The response [types] and endpoint builders are always available. The HTTP
+//! clients are enabled by cargo features:
+//!
+//! - `blocking`: [`OctaneZsrClient`], using reqwest's blocking client
+//! - `async`: [`OctaneZsrClientAsync`] and [`OctaneZsrClientBlocking`]
+//! - `native-tls` or `rustls`: the TLS backend used by the clients
+//! - `extra-fields`: an `extra` map on the response [types], holding fields
+//!   not modelled by this crate
+//!
+//! All of `blocking`, `async` and `native-tls` are enabled by default.
 
 pub mod api;
+#[cfg(any(feature = "blocking", feature = "async"))]
+pub mod cache;
+pub mod cassette;
+#[cfg(any(feature = "blocking", feature = "async"))]
 mod client;
 pub mod error;
+pub mod mock;
+#[cfg(any(feature = "blocking", feature = "async"))]
+mod rate_limit;
+#[cfg(any(feature = "blocking", feature = "async"))]
+mod retry;
 pub mod types;
 
 pub use api::pagination::PagedEndpointExt;
-pub use client::{OctaneZsrBuilder, OctaneZsrClient, OctaneZsrClientAsync};
+#[cfg(any(feature = "blocking", feature = "async"))]
+pub use client::OctaneZsrBuilder;
+#[cfg(feature = "blocking")]
+pub use client::OctaneZsrClient;
+#[cfg(feature = "async")]
+pub use client::{OctaneZsrClientAsync, OctaneZsrClientBlocking};
+#[cfg(any(feature = "blocking", feature = "async"))]
+pub use rate_limit::{RateLimitStats, RateLimiter};
+#[cfg(any(feature = "blocking", feature = "async"))]
+pub use retry::{RetryPolicy, RetryableError};
+
+pub(crate) const ZSR_API_BASE_URL: &str = "https://zsr.octane.gg/";
@@ -0,0 +1,396 @@
+//! A programmable mock client for tests
+//!
+//! Register [`Expectation`]s on a [`MockClient`], run the code under test,
+//! then check which requests were made.
+//!
+//! # Example
+//!
+//! ```rust
+//! use octanezsr_api::{
+//!     api::{matches::Match, Query},
+//!     mock::{Expectation, MockClient},
+//! };
+//! use serde_json::{json, Value};
+//!
+//! let client = MockClient::new();
+//! client.expect(Expectation::get("/matches/abc").json(&json!({"_id": "abc"})));
+//! client.expect(Expectation::get("/matches/missing").status(404));
+//!
+//! let endpoint = Match::builder().id("abc").build().unwrap();
+//! let value: Value = endpoint.query(&client).unwrap();
+//! assert_eq!(value["_id"], "abc");
+//!
+//! let endpoint = Match::builder().id("missing").build().unwrap();
+//! assert!(Query::<Value, _>::query(&endpoint, &client).is_err());
+//!
+//! client.assert_requests(&["/matches/abc", "/matches/missing"]);
+//! ```
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::{request::Builder as RequestBuilder, Method, Response, StatusCode};
+use serde::Serialize;
+use thiserror::Error;
+use url::Url;
+
+use crate::{
+    api::{self, ApiError},
+    ZSR_API_BASE_URL,
+};
+
+/// Errors from the [`MockClient`]
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum MockError {
+    /// No [`Expectation`] matched a request
+    #[error("unexpected request: {0}")]
+    Unexpected(String),
+    /// Error building a response
+    #[error("HTTP error: {0}")]
+    Http(#[from] http::Error),
+}
+
+/// An expected request and the canned response to return for it
+#[derive(Debug, Clone)]
+pub struct Expectation {
+    method: Method,
+    path: String,
+    query: Option<String>,
+    status: StatusCode,
+    body: Bytes,
+    times: Option<usize>,
+    hits: usize,
+}
+
+impl Expectation {
+    /// Expect a `GET` request to `path`. Example: `"/matches"`
+    ///
+    /// The expectation matches any query string any number of times, and
+    /// responds with `200 OK` and an empty JSON object.
+    pub fn get<P>(path: P) -> Self
+    where
+        P: AsRef<str>,
+    {
+        Self {
+            method: Method::GET,
+            path: format!("/{}", path.as_ref().trim_start_matches('/')),
+            query: None,
+            status: StatusCode::OK,
+            body: Bytes::from_static(b"{}"),
+            times: None,
+            hits: 0,
+        }
+    }
+
+    /// Only match requests with exactly this query string. Example:
+    /// `"event=abc&page=2"`
+    ///
+    /// An empty string matches requests without a query string.
+    pub fn query<Q>(mut self, query: Q) -> Self
+    where
+        Q: Into<String>,
+    {
+        self.query = Some(query.into());
+        self
+    }
+
+    /// Only match the first `times` requests. Example: `1`
+    ///
+    /// Once used up, later requests fall through to the expectations
+    /// registered after this one, so responses can be scripted in sequence.
+    pub fn times(mut self, times: usize) -> Self {
+        self.times = Some(times);
+        self
+    }
+
+    /// Status code of the response. Example: `404`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `status` is not a valid status code.
+    pub fn status<S>(mut self, status: S) -> Self
+    where
+        S: TryInto<StatusCode>,
+        S::Error: std::fmt::Debug,
+    {
+        self.status = status.try_into().expect("invalid status code");
+        self
+    }
+
+    /// Respond with `value` serialized as JSON
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` can not be serialized.
+    pub fn json<T>(self, value: &T) -> Self
+    where
+        T: Serialize + ?Sized,
+    {
+        self.body(serde_json::to_vec(value).expect("failed to serialize mock response"))
+    }
+
+    /// Respond with a raw body
+    pub fn body<B>(mut self, body: B) -> Self
+    where
+        B: Into<Bytes>,
+    {
+        self.body = body.into();
+        self
+    }
+
+    fn matches(&self, request: &MockRequest) -> bool {
+        self.method == request.method
+            && self.path == request.path
+            && match &self.query {
+                Some(query) => query == request.query.as_deref().unwrap_or(""),
+                None => true,
+            }
+    }
+
+    fn is_used_up(&self) -> bool {
+        matches!(self.times, Some(times) if self.hits >= times)
+    }
+
+    fn is_met(&self) -> bool {
+        match self.times {
+            Some(times) => self.hits >= times,
+            None => self.hits > 0,
+        }
+    }
+}
+
+impl std::fmt::Display for Expectation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.method, self.path)?;
+        if let Some(query) = &self.query {
+            write!(f, "?{}", query)?;
+        }
+        if let Some(times) = self.times {
+            write!(f, " ({} of {} times)", self.hits, times)?;
+        }
+        Ok(())
+    }
+}
+
+/// A request received by a [`MockClient`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockRequest {
+    /// Request method
+    pub method: Method,
+    /// Request path. Example: `"/matches"`
+    pub path: String,
+    /// Request query string, if any. Example: `"event=abc&page=2"`
+    pub query: Option<String>,
+}
+
+impl std::fmt::Display for MockRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.query {
+            Some(query) => write!(f, "{}?{}", self.path, query),
+            None => write!(f, "{}", self.path),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    expectations: Vec<Expectation>,
+    requests: Vec<MockRequest>,
+}
+
+/// A client returning canned responses
+///
+/// Requests are matched against expectations in the order they were
+/// registered, skipping expectations that are used up. Clones share
+/// expectations and recorded requests.
+#[derive(Debug, Clone, Default)]
+pub struct MockClient {
+    state: Arc<Mutex<MockState>>,
+}
+
+impl MockClient {
+    /// Create a new [`MockClient`] without any expectations
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register an expected request
+    pub fn expect(&self, expectation: Expectation) -> &Self {
+        self.lock().expectations.push(expectation);
+        self
+    }
+
+    /// All requests received so far, in order
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.lock().requests.clone()
+    }
+
+    /// Assert that exactly these requests were received, in order, and that
+    /// every expectation was met. Requests are formatted as `path?query`.
+    /// Example: `["/matches?page=2"]`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the received requests differ, or if an expectation was never
+    /// matched or matched fewer than its [`times`](Expectation::times).
+    #[track_caller]
+    pub fn assert_requests(&self, expected: &[&str]) {
+        let requests: Vec<_> = self.requests().iter().map(ToString::to_string).collect();
+        assert_eq!(requests, expected, "unexpected requests to MockClient");
+
+        let unmet: Vec<_> = self
+            .lock()
+            .expectations
+            .iter()
+            .filter(|expectation| !expectation.is_met())
+            .map(ToString::to_string)
+            .collect();
+        assert!(
+            unmet.is_empty(),
+            "unmet expectations of MockClient: {:?}",
+            unmet
+        );
+    }
+
+    fn respond(&self, request: &RequestBuilder) -> Result<Response<Bytes>, ApiError<MockError>> {
+        let uri = request.uri_ref().cloned().unwrap_or_default();
+        let request = MockRequest {
+            method: request.method_ref().cloned().unwrap_or_default(),
+            path: uri.path().to_string(),
+            query: uri.query().map(ToString::to_string),
+        };
+
+        let mut state = self.lock();
+        let expectation = state
+            .expectations
+            .iter_mut()
+            .find(|expectation| !expectation.is_used_up() && expectation.matches(&request))
+            .map(|expectation| {
+                expectation.hits += 1;
+                expectation.clone()
+            });
+        let description = request.to_string();
+        state.requests.push(request);
+        drop(state);
+
+        let expectation =
+            expectation.ok_or_else(|| ApiError::client(MockError::Unexpected(description)))?;
+        Response::builder()
+            .status(expectation.status)
+            .body(expectation.body)
+            .map_err(|err| ApiError::client(err.into()))
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, MockState> {
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+impl api::RestClient for MockClient {
+    type Error = MockError;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        Ok(Url::parse(ZSR_API_BASE_URL)?.join(endpoint.trim_start_matches('/'))?)
+    }
+}
+
+impl api::Client for MockClient {
+    fn rest(
+        &self,
+        request: RequestBuilder,
+        _body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        self.respond(&request)
+    }
+}
+
+#[async_trait]
+impl api::AsyncClient for MockClient {
+    async fn rest_async(
+        &self,
+        request: RequestBuilder,
+        _body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        self.respond(&request)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::api::{teams::Team, AsyncQuery, Query};
+
+    #[test]
+    fn matches_in_registration_order() {
+        let client = MockClient::new();
+        client
+            .expect(
+                Expectation::get("teams/abc")
+                    .query("")
+                    .times(1)
+                    .json(&json!({"n": 1})),
+            )
+            .expect(Expectation::get("/teams/abc").json(&json!({"n": 2})));
+
+        let endpoint = Team::builder().id("abc").build().unwrap();
+        let first: Value = endpoint.query(&client).unwrap();
+        let second: Value = endpoint.query(&client).unwrap();
+        let third: Value = endpoint.query(&client).unwrap();
+
+        assert_eq!(first, json!({"n": 1}));
+        assert_eq!(second, json!({"n": 2}));
+        assert_eq!(third, json!({"n": 2}));
+        client.assert_requests(&["/teams/abc", "/teams/abc", "/teams/abc"]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = r#"unmet expectations of MockClient: ["GET /teams/abc (1 of 2 times)", "GET /teams/def"]"#
+    )]
+    fn unmet_expectations() {
+        let client = MockClient::new();
+        client
+            .expect(Expectation::get("/teams/abc").times(2))
+            .expect(Expectation::get("/teams/def"));
+
+        let endpoint = Team::builder().id("abc").build().unwrap();
+        let _: Value = endpoint.query(&client).unwrap();
+
+        client.assert_requests(&["/teams/abc"]);
+    }
+
+    #[tokio::test]
+    async fn unexpected_request() {
+        let client = MockClient::new();
+        client.expect(Expectation::get("/teams/abc"));
+
+        let endpoint = Team::builder().id("def").build().unwrap();
+        let err = AsyncQuery::<Value, _>::query_async(&endpoint, &client)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, ApiError::Client(MockError::Unexpected(url)) if url == "/teams/def"));
+        assert_eq!(
+            client.requests(),
+            [MockRequest {
+                method: Method::GET,
+                path: "/teams/def".into(),
+                query: None
+            }]
+        );
+    }
+
+    #[test]
+    fn canned_status() {
+        let client = MockClient::new();
+        client.expect(Expectation::get("/teams/abc").status(404));
+
+        let endpoint = Team::builder().id("abc").build().unwrap();
+        let err = Query::<Value, _>::query(&endpoint, &client).unwrap_err();
+
+        assert!(matches!(err, ApiError::Response(_)));
+    }
+}
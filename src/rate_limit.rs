@@ -0,0 +1,180 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use log::debug;
+
+/// A token bucket rate limiter
+///
+/// Cloning a [`RateLimiter`] returns a handle to the same bucket, so a single
+/// limiter can be shared between several blocking and asynchronous clients.
+///
+/// # Example
+///
+#[cfg_attr(all(feature = "blocking", feature = "async"), doc = "```rust ,no_run")]
+#[cfg_attr(
+    not(all(feature = "blocking", feature = "async")),
+    doc = "```rust ,ignore"
+)]
+/// use octanezsr_api::{OctaneZsrBuilder, RateLimiter};
+///
+/// # fn main() -> octanezsr_api::error::OctaneZsrResult<()> {
+/// // Allow 5 requests per second, with bursts of up to 10 requests.
+/// let limiter = RateLimiter::new(5.0, 10);
+/// let builder = OctaneZsrBuilder::new().rate_limiter(limiter.clone());
+/// let client = builder.build()?;
+/// let async_client = builder.build_async()?;
+///
+/// println!("{:?}", limiter.stats().total_wait);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    rate: f64,
+    burst: f64,
+    tokens: f64,
+    updated: Instant,
+    stats: RateLimitStats,
+}
+
+/// Statistics collected by a [`RateLimiter`]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitStats {
+    /// Number of requests that passed through the limiter
+    pub requests: u64,
+    /// Number of requests that had to wait
+    pub throttled: u64,
+    /// Total time spent waiting
+    pub total_wait: Duration,
+    /// Longest single wait
+    pub max_wait: Duration,
+}
+
+impl RateLimiter {
+    /// Create a new [`RateLimiter`] allowing `requests_per_second` requests
+    /// on average, and bursts of up to `burst` requests.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `requests_per_second` is not a positive number.
+    pub fn new(requests_per_second: f64, burst: u32) -> Self {
+        assert!(
+            requests_per_second > 0.0,
+            "rate limit must be a positive number of requests per second"
+        );
+
+        let burst = f64::from(burst.max(1));
+        Self {
+            bucket: Arc::new(Mutex::new(Bucket {
+                rate: requests_per_second,
+                burst,
+                tokens: burst,
+                updated: Instant::now(),
+                stats: RateLimitStats::default(),
+            })),
+        }
+    }
+
+    /// Statistics for all requests made through this limiter
+    pub fn stats(&self) -> RateLimitStats {
+        self.lock().stats
+    }
+
+    /// Block the current thread until a request is allowed.
+    #[cfg(feature = "blocking")]
+    pub(crate) fn wait(&self) {
+        let delay = self.reserve();
+        if !delay.is_zero() {
+            std::thread::sleep(delay);
+        }
+    }
+
+    /// Wait asynchronously until a request is allowed.
+    #[cfg(feature = "async")]
+    pub(crate) async fn wait_async(&self) {
+        let delay = self.reserve();
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    /// Take a token from the bucket, returning how long the caller has to wait
+    /// before it may send its request.
+    ///
+    /// The bucket is allowed to go into debt, so concurrent callers queue up
+    /// behind each other instead of racing for the next token.
+    fn reserve(&self) -> Duration {
+        let mut bucket = self.lock();
+
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * bucket.rate).min(bucket.burst);
+        bucket.updated = now;
+        bucket.tokens -= 1.0;
+
+        let delay = if bucket.tokens < 0.0 {
+            Duration::from_secs_f64(-bucket.tokens / bucket.rate)
+        } else {
+            Duration::ZERO
+        };
+
+        bucket.stats.requests += 1;
+        if !delay.is_zero() {
+            debug!("rate limited, waiting {:?}", delay);
+            bucket.stats.throttled += 1;
+            bucket.stats.total_wait += delay;
+            bucket.stats.max_wait = bucket.stats.max_wait.max(delay);
+        }
+
+        delay
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Bucket> {
+        // The bucket is always left in a consistent state, so a poisoned lock
+        // is safe to reuse
+        self.bucket.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn burst_then_throttle() {
+        let limiter = RateLimiter::new(2.0, 3);
+
+        for _ in 0..3 {
+            assert_eq!(limiter.reserve(), Duration::ZERO);
+        }
+
+        let delay = limiter.reserve();
+        assert!(delay > Duration::from_millis(400) && delay <= Duration::from_millis(500));
+
+        // Waiters queue up behind each other
+        let delay = limiter.reserve();
+        assert!(delay > Duration::from_millis(900) && delay <= Duration::from_millis(1000));
+
+        let stats = limiter.stats();
+        assert_eq!(stats.requests, 5);
+        assert_eq!(stats.throttled, 2);
+        assert_eq!(stats.max_wait, delay);
+    }
+
+    #[test]
+    fn shared_between_clones() {
+        let limiter = RateLimiter::new(1.0, 1);
+        let clone = limiter.clone();
+
+        assert_eq!(limiter.reserve(), Duration::ZERO);
+        assert!(clone.reserve() > Duration::ZERO);
+        assert_eq!(limiter.stats(), clone.stats());
+    }
+}
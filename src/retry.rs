@@ -0,0 +1,335 @@
+use std::time::Duration;
+
+use bytes::Bytes;
+use http::{Response, StatusCode};
+use rand::Rng;
+
+use crate::{api::utils::retry_after, error::RestError};
+
+/// Kinds of communication errors that can be retried
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RetryableError {
+    /// The request timed out
+    Timeout,
+    /// A connection to the server could not be established
+    Connect,
+    /// The connection failed while sending the request or reading the
+    /// response, e.g. a connection reset
+    Connection,
+}
+
+impl RetryableError {
+    fn classify(err: &reqwest::Error) -> Option<Self> {
+        if err.is_timeout() {
+            Some(Self::Timeout)
+        } else if err.is_connect() {
+            Some(Self::Connect)
+        } else if err.is_request() || err.is_body() {
+            Some(Self::Connection)
+        } else {
+            None
+        }
+    }
+}
+
+/// Policy for retrying failed requests
+///
+/// Retries use an exponential backoff starting at
+/// [`initial_backoff`](Self::initial_backoff), doubling with every attempt
+/// up to [`max_backoff`](Self::max_backoff).
+///
+/// # Example
+///
+#[cfg_attr(feature = "blocking", doc = "```rust ,no_run")]
+#[cfg_attr(not(feature = "blocking"), doc = "```rust ,ignore")]
+/// use std::time::Duration;
+///
+/// use octanezsr_api::{OctaneZsrBuilder, RetryPolicy};
+///
+/// # fn main() -> octanezsr_api::error::OctaneZsrResult<()> {
+/// let client = OctaneZsrBuilder::new()
+///     .retry(
+///         RetryPolicy::new()
+///             .max_attempts(5)
+///             .initial_backoff(Duration::from_secs(1)),
+///     )
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+    statuses: Vec<StatusCode>,
+    errors: Vec<RetryableError>,
+    respect_retry_after: bool,
+}
+
+impl RetryPolicy {
+    /// Create a new [`RetryPolicy`]
+    ///
+    /// By default a request is attempted up to 3 times, starting with a
+    /// backoff of 500ms. `429`, `500`, `502`, `503` and `504` responses and
+    /// all [`RetryableError`] kinds are retried.
+    pub fn new() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            jitter: true,
+            statuses: vec![
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::INTERNAL_SERVER_ERROR,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+            errors: vec![
+                RetryableError::Timeout,
+                RetryableError::Connect,
+                RetryableError::Connection,
+            ],
+            respect_retry_after: true,
+        }
+    }
+
+    /// Maximum number of attempts, including the first request. Example: `3`
+    pub fn max_attempts(mut self, value: u32) -> Self {
+        self.max_attempts = value.max(1);
+        self
+    }
+
+    /// Backoff before the first retry. Example: `Duration::from_millis(500)`
+    pub fn initial_backoff(mut self, value: Duration) -> Self {
+        self.initial_backoff = value;
+        self
+    }
+
+    /// Upper bound for the backoff between attempts. Example:
+    /// `Duration::from_secs(30)`
+    pub fn max_backoff(mut self, value: Duration) -> Self {
+        self.max_backoff = value;
+        self
+    }
+
+    /// Randomize each backoff between half and all of its nominal value.
+    /// Example: `true`
+    pub fn jitter(mut self, value: bool) -> Self {
+        self.jitter = value;
+        self
+    }
+
+    /// HTTP status codes that are retried. Example:
+    /// `[StatusCode::BAD_GATEWAY]`
+    pub fn retry_statuses<I>(mut self, statuses: I) -> Self
+    where
+        I: IntoIterator<Item = StatusCode>,
+    {
+        self.statuses = statuses.into_iter().collect();
+        self
+    }
+
+    /// Communication errors that are retried. Example:
+    /// `[RetryableError::Timeout]`
+    pub fn retry_errors<I>(mut self, errors: I) -> Self
+    where
+        I: IntoIterator<Item = RetryableError>,
+    {
+        self.errors = errors.into_iter().collect();
+        self
+    }
+
+    /// Wait for the delay requested by a `Retry-After` header instead of the
+    /// computed backoff. A response asking for a longer delay than
+    /// [`max_backoff`](Self::max_backoff) is not retried. Example: `true`
+    pub fn respect_retry_after(mut self, value: bool) -> Self {
+        self.respect_retry_after = value;
+        self
+    }
+
+    /// Returns how long to wait before retrying, or `None` if the result of
+    /// `attempt` (counting from 0) should be returned as-is.
+    pub(crate) fn retry_delay(
+        &self,
+        attempt: u32,
+        result: &Result<Response<Bytes>, RestError>,
+    ) -> Option<Duration> {
+        if !self.can_retry(attempt) {
+            return None;
+        }
+
+        match result {
+            Ok(rsp) if self.statuses.contains(&rsp.status()) => {
+                match retry_after(rsp.headers()).filter(|_| self.respect_retry_after) {
+                    Some(delay) if delay > self.max_backoff => None,
+                    Some(delay) => Some(delay),
+                    None => Some(self.backoff(attempt)),
+                }
+            }
+            Err(RestError::Communication(err)) => RetryableError::classify(err)
+                .filter(|kind| self.errors.contains(kind))
+                .map(|_| self.backoff(attempt)),
+            _ => None,
+        }
+    }
+
+    /// Whether `attempt` (counting from 0) may be followed by another one
+    fn can_retry(&self, attempt: u32) -> bool {
+        attempt.saturating_add(1) < self.max_attempts
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_backoff);
+
+        if self.jitter {
+            let half = backoff / 2;
+            half + rand::thread_rng().gen_range(Duration::ZERO..=half)
+        } else {
+            backoff
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Attempts of a single request under an optional [`RetryPolicy`]
+pub(crate) struct Attempts<'a> {
+    policy: Option<&'a RetryPolicy>,
+    attempt: u32,
+}
+
+impl<'a> Attempts<'a> {
+    pub(crate) fn new(policy: Option<&'a RetryPolicy>) -> Self {
+        Self { policy, attempt: 0 }
+    }
+
+    /// Whether the current attempt may be retried, i.e. whether a copy of the
+    /// request needs to be kept around
+    pub(crate) fn may_retry(&self) -> bool {
+        self.policy
+            .is_some_and(|policy| policy.can_retry(self.attempt))
+    }
+
+    /// Returns how long to wait before the next attempt, or `None` if `result`
+    /// should be returned as-is.
+    pub(crate) fn next_delay(
+        &mut self,
+        result: &Result<Response<Bytes>, RestError>,
+    ) -> Option<Duration> {
+        let delay = self.policy?.retry_delay(self.attempt, result)?;
+        self.attempt += 1;
+        Some(delay)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use http::header;
+
+    use super::*;
+
+    fn response(
+        status: StatusCode,
+        retry_after: Option<&str>,
+    ) -> Result<Response<Bytes>, RestError> {
+        let mut builder = Response::builder().status(status);
+        if let Some(value) = retry_after {
+            builder = builder.header(header::RETRY_AFTER, value);
+        }
+        Ok(builder.body(Bytes::new()).unwrap())
+    }
+
+    #[test]
+    fn exponential_backoff() {
+        let policy = RetryPolicy::new()
+            .max_attempts(10)
+            .initial_backoff(Duration::from_secs(1))
+            .max_backoff(Duration::from_secs(5))
+            .jitter(false);
+        let rsp = response(StatusCode::BAD_GATEWAY, None);
+
+        assert_eq!(policy.retry_delay(0, &rsp), Some(Duration::from_secs(1)));
+        assert_eq!(policy.retry_delay(1, &rsp), Some(Duration::from_secs(2)));
+        assert_eq!(policy.retry_delay(2, &rsp), Some(Duration::from_secs(4)));
+        assert_eq!(policy.retry_delay(3, &rsp), Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn jittered_backoff() {
+        let policy = RetryPolicy::new().initial_backoff(Duration::from_secs(2));
+        let rsp = response(StatusCode::BAD_GATEWAY, None);
+
+        let delay = policy.retry_delay(0, &rsp).unwrap();
+        assert!(delay >= Duration::from_secs(1) && delay <= Duration::from_secs(2));
+    }
+
+    #[test]
+    fn max_attempts() {
+        let policy = RetryPolicy::new().max_attempts(2);
+        let rsp = response(StatusCode::SERVICE_UNAVAILABLE, None);
+
+        assert!(policy.retry_delay(0, &rsp).is_some());
+        assert!(policy.retry_delay(1, &rsp).is_none());
+    }
+
+    #[test]
+    fn non_retryable_status() {
+        let policy = RetryPolicy::new();
+
+        assert!(policy
+            .retry_delay(0, &response(StatusCode::OK, None))
+            .is_none());
+        assert!(policy
+            .retry_delay(0, &response(StatusCode::NOT_FOUND, None))
+            .is_none());
+
+        let policy = policy.retry_statuses([StatusCode::NOT_FOUND]);
+        assert!(policy
+            .retry_delay(0, &response(StatusCode::NOT_FOUND, None))
+            .is_some());
+    }
+
+    #[test]
+    fn honours_retry_after() {
+        let policy = RetryPolicy::new();
+
+        let rsp = response(StatusCode::TOO_MANY_REQUESTS, Some("7"));
+        assert_eq!(policy.retry_delay(0, &rsp), Some(Duration::from_secs(7)));
+
+        let rsp = response(StatusCode::TOO_MANY_REQUESTS, Some("3600"));
+        assert_eq!(policy.retry_delay(0, &rsp), None);
+
+        let rsp = response(
+            StatusCode::TOO_MANY_REQUESTS,
+            Some("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(policy.retry_delay(0, &rsp), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn attempts() {
+        let rsp = response(StatusCode::SERVICE_UNAVAILABLE, None);
+
+        let mut attempts = Attempts::new(None);
+        assert!(!attempts.may_retry());
+        assert!(attempts.next_delay(&rsp).is_none());
+
+        let policy = RetryPolicy::new().max_attempts(2).jitter(false);
+        let mut attempts = Attempts::new(Some(&policy));
+        assert!(attempts.may_retry());
+        assert_eq!(attempts.next_delay(&rsp), Some(Duration::from_millis(500)));
+        assert!(!attempts.may_retry());
+        assert!(attempts.next_delay(&rsp).is_none());
+    }
+}
@@ -1,6 +1,6 @@
 #![allow(missing_docs)]
 
-use std::fmt::Display;
+use std::{collections::BTreeMap, fmt::Display};
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -16,24 +16,46 @@ use crate::api::{
     teams::TeamId,
 };
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct Event<'a> {
-    #[serde(rename = "_id")]
-    pub id: EventId<'a>,
-    pub slug: String,
-    pub name: Option<String>,
-    pub start_date: Option<DateTime<Utc>>,
-    pub end_date: Option<DateTime<Utc>>,
-    pub region: Region,
-    pub mode: Mode,
-    pub prize: Option<Prize>,
-    pub tier: Tier,
-    pub image: Option<Url>,
-    #[serde(default)]
-    pub stages: Vec<Stage>,
-    #[serde(default)]
-    pub groups: Vec<String>,
+/// Define a response type with an `extra` field, capturing the fields not
+/// modelled by this crate when the `extra-fields` feature is enabled
+macro_rules! response_type {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident $(<$lt:lifetime>)? {
+            $($(#[$field_meta:meta])* pub $field:ident: $ty:ty,)*
+        }
+    ) => {
+        $(#[$meta])*
+        pub struct $name $(<$lt>)? {
+            $($(#[$field_meta])* pub $field: $ty,)*
+            /// Fields not modelled by this crate
+            #[cfg(feature = "extra-fields")]
+            #[serde(flatten)]
+            pub extra: serde_json::Map<String, serde_json::Value>,
+        }
+    };
+}
+
+response_type! {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Event<'a> {
+        #[serde(rename = "_id")]
+        pub id: EventId<'a>,
+        pub slug: String,
+        pub name: Option<String>,
+        pub start_date: Option<DateTime<Utc>>,
+        pub end_date: Option<DateTime<Utc>>,
+        pub region: Region,
+        pub mode: Mode,
+        pub prize: Option<Prize>,
+        pub tier: Tier,
+        pub image: Option<Url>,
+        #[serde(default)]
+        pub stages: Vec<Stage>,
+        #[serde(default)]
+        pub groups: Vec<String>,
+    }
 }
 
 impl<'a> From<Event<'a>> for EventId<'a> {
@@ -49,25 +71,27 @@ pub struct Prize {
     pub currency: String,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct Stage {
-    #[serde(rename = "_id")]
-    pub id: StageId,
-    pub name: String,
-    pub format: Option<String>,
-    pub region: Option<Region>,
-    pub start_date: Option<DateTime<Utc>>,
-    pub end_date: Option<DateTime<Utc>>,
-    pub liquipedia: Option<Url>,
-    #[serde(default)]
-    pub substages: Vec<Substage>,
-    pub prize: Option<Prize>,
-    #[serde(default)]
-    pub qualifier: bool,
-    #[serde(default)]
-    pub lan: bool,
-    pub location: Option<Location>,
+response_type! {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Stage {
+        #[serde(rename = "_id")]
+        pub id: StageId,
+        pub name: String,
+        pub format: Option<String>,
+        pub region: Option<Region>,
+        pub start_date: Option<DateTime<Utc>>,
+        pub end_date: Option<DateTime<Utc>>,
+        pub liquipedia: Option<Url>,
+        #[serde(default)]
+        pub substages: Vec<Substage>,
+        pub prize: Option<Prize>,
+        #[serde(default)]
+        pub qualifier: bool,
+        #[serde(default)]
+        pub lan: bool,
+        pub location: Option<Location>,
+    }
 }
 
 impl From<Stage> for StageId {
@@ -99,25 +123,27 @@ pub struct Location {
     pub country: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct Match<'a> {
-    #[serde(rename = "_id")]
-    pub id: MatchId<'a>,
-    pub slug: String,
-    #[serde(rename = "octane_id")]
-    pub octane_id: Option<String>,
-    pub event: Event<'a>,
-    pub stage: Stage,
-    pub date: Option<DateTime<Utc>>,
-    pub format: Option<Format>,
-    pub blue: Option<Side<'a>>,
-    pub orange: Option<Side<'a>>,
-    pub number: Option<i64>,
-    #[serde(default)]
-    pub games: Vec<GameScore<'a>>,
-    pub reverse_sweep_attempt: Option<bool>,
-    pub reverse_sweep: Option<bool>,
+response_type! {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Match<'a> {
+        #[serde(rename = "_id")]
+        pub id: MatchId<'a>,
+        pub slug: String,
+        #[serde(rename = "octane_id")]
+        pub octane_id: Option<String>,
+        pub event: Event<'a>,
+        pub stage: Stage,
+        pub date: Option<DateTime<Utc>>,
+        pub format: Option<Format>,
+        pub blue: Option<Side<'a>>,
+        pub orange: Option<Side<'a>>,
+        pub number: Option<i64>,
+        #[serde(default)]
+        pub games: Vec<GameScore<'a>>,
+        pub reverse_sweep_attempt: Option<bool>,
+        pub reverse_sweep: Option<bool>,
+    }
 }
 
 impl<'a> From<Match<'a>> for MatchId<'a> {
@@ -157,17 +183,19 @@ pub struct TeamInfo<'a> {
     pub stats: Option<TeamStats>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct Team<'a> {
-    #[serde(rename = "_id")]
-    pub id: TeamId<'a>,
-    pub slug: Option<String>,
-    pub name: String,
-    pub image: Option<Url>,
-    pub region: Option<Region>,
-    #[serde(default)]
-    pub relevant: bool,
+response_type! {
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Team<'a> {
+        #[serde(rename = "_id")]
+        pub id: TeamId<'a>,
+        pub slug: Option<String>,
+        pub name: String,
+        pub image: Option<Url>,
+        pub region: Option<Region>,
+        #[serde(default)]
+        pub relevant: bool,
+    }
 }
 
 impl<'a> From<Team<'a>> for TeamId<'a> {
@@ -176,88 +204,100 @@ impl<'a> From<Team<'a>> for TeamId<'a> {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct TeamStats {
-    pub core: CoreStats,
-    pub boost: Option<TeamBoostStats>,
-    pub ball: Option<BallStats>,
-    pub movement: Option<TeamMovementStats>,
-    pub positioning: Option<PositioningStats>,
-    pub demo: Option<DemoStats>,
+response_type! {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct TeamStats {
+        pub core: CoreStats,
+        pub boost: Option<TeamBoostStats>,
+        pub ball: Option<BallStats>,
+        pub movement: Option<TeamMovementStats>,
+        pub positioning: Option<PositioningStats>,
+        pub demo: Option<DemoStats>,
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct CoreStats {
-    pub shots: i64,
-    pub goals: i64,
-    pub saves: i64,
-    pub assists: i64,
-    pub score: f64, /* Only integer values are possible in RL, but there are
-                     * errenous float values in the Octane.gg database
-                     */
-    pub shooting_percentage: f64,
+response_type! {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct CoreStats {
+        pub shots: i64,
+        pub goals: i64,
+        pub saves: i64,
+        pub assists: i64,
+        pub score: f64, /* Only integer values are possible in RL, but there are
+                         * errenous float values in the Octane.gg database
+                         */
+        pub shooting_percentage: f64,
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct TeamBoostStats {
-    pub bpm: i64,
-    pub bcpm: f64,
-    pub avg_amount: f64,
-    pub amount_collected: i64,
-    pub amount_stolen: i64,
-    pub amount_collected_big: i64,
-    pub amount_stolen_big: i64,
-    pub amount_collected_small: i64,
-    pub amount_stolen_small: i64,
-    pub count_collected_big: i64,
-    pub count_stolen_big: i64,
-    pub count_collected_small: i64,
-    pub count_stolen_small: i64,
-    pub amount_overfill: i64,
-    pub amount_overfill_stolen: i64,
-    pub amount_used_while_supersonic: i64,
-    pub time_zero_boost: f64,
-    pub time_full_boost: f64,
-    pub time_boost_0_to_25: f64,
-    pub time_boost_25_to_50: f64,
-    pub time_boost_50_to_75: f64,
-    pub time_boost_75_to_100: f64,
+response_type! {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct TeamBoostStats {
+        pub bpm: i64,
+        pub bcpm: f64,
+        pub avg_amount: f64,
+        pub amount_collected: i64,
+        pub amount_stolen: i64,
+        pub amount_collected_big: i64,
+        pub amount_stolen_big: i64,
+        pub amount_collected_small: i64,
+        pub amount_stolen_small: i64,
+        pub count_collected_big: i64,
+        pub count_stolen_big: i64,
+        pub count_collected_small: i64,
+        pub count_stolen_small: i64,
+        pub amount_overfill: i64,
+        pub amount_overfill_stolen: i64,
+        pub amount_used_while_supersonic: i64,
+        pub time_zero_boost: f64,
+        pub time_full_boost: f64,
+        pub time_boost_0_to_25: f64,
+        pub time_boost_25_to_50: f64,
+        pub time_boost_50_to_75: f64,
+        pub time_boost_75_to_100: f64,
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct TeamMovementStats {
-    pub total_distance: i64,
-    pub time_supersonic_speed: f64,
-    pub time_boost_speed: f64,
-    pub time_slow_speed: f64,
-    pub time_ground: f64,
-    pub time_low_air: f64,
-    pub time_high_air: f64,
-    pub time_powerslide: f64,
-    pub count_powerslide: i64,
+response_type! {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct TeamMovementStats {
+        pub total_distance: i64,
+        pub time_supersonic_speed: f64,
+        pub time_boost_speed: f64,
+        pub time_slow_speed: f64,
+        pub time_ground: f64,
+        pub time_low_air: f64,
+        pub time_high_air: f64,
+        pub time_powerslide: f64,
+        pub count_powerslide: i64,
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct PositioningStats {
-    pub time_defensive_third: f64,
-    pub time_neutral_third: f64,
-    pub time_offensive_third: f64,
-    pub time_defensive_half: f64,
-    pub time_offensive_half: f64,
-    pub time_behind_ball: f64,
-    pub time_infront_ball: f64,
+response_type! {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct PositioningStats {
+        pub time_defensive_third: f64,
+        pub time_neutral_third: f64,
+        pub time_offensive_third: f64,
+        pub time_defensive_half: f64,
+        pub time_offensive_half: f64,
+        pub time_behind_ball: f64,
+        pub time_infront_ball: f64,
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct DemoStats {
-    pub inflicted: i64,
-    pub taken: i64,
+response_type! {
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct DemoStats {
+        pub inflicted: i64,
+        pub taken: i64,
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -268,24 +308,26 @@ pub struct PlayerInfo<'a> {
     pub advanced: AdvancedStats,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct Player<'a> {
-    #[serde(rename = "_id")]
-    pub id: PlayerId<'a>,
-    pub slug: Option<String>,
-    pub tag: String,
-    pub country: Option<String>,
-    pub name: Option<String>,
-    #[serde(default)]
-    pub accounts: Vec<Account>,
-    #[serde(default)]
-    pub relevant: bool,
-    pub team: Option<Team<'a>>,
-    #[serde(default)]
-    pub substitute: bool,
-    #[serde(default)]
-    pub coach: bool,
+response_type! {
+    #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Player<'a> {
+        #[serde(rename = "_id")]
+        pub id: PlayerId<'a>,
+        pub slug: Option<String>,
+        pub tag: String,
+        pub country: Option<String>,
+        pub name: Option<String>,
+        #[serde(default)]
+        pub accounts: Vec<Account>,
+        #[serde(default)]
+        pub relevant: bool,
+        pub team: Option<Team<'a>>,
+        #[serde(default)]
+        pub substitute: bool,
+        #[serde(default)]
+        pub coach: bool,
+    }
 }
 
 impl<'a> From<Player<'a>> for PlayerId<'a> {
@@ -301,111 +343,121 @@ pub struct Account {
     pub id: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct PlayerStats {
-    pub core: CoreStats,
-    pub boost: Option<PlayerBoostStats>,
-    pub movement: Option<PlayerMovementStats>,
-    pub positioning: Option<PlayerPositioningStats>,
-    pub demo: Option<DemoStats>,
+response_type! {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct PlayerStats {
+        pub core: CoreStats,
+        pub boost: Option<PlayerBoostStats>,
+        pub movement: Option<PlayerMovementStats>,
+        pub positioning: Option<PlayerPositioningStats>,
+        pub demo: Option<DemoStats>,
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct PlayerBoostStats {
-    pub bpm: i64,
-    pub bcpm: f64,
-    pub avg_amount: f64,
-    pub amount_collected: i64,
-    pub amount_stolen: i64,
-    pub amount_collected_big: i64,
-    pub amount_stolen_big: i64,
-    pub amount_collected_small: i64,
-    pub amount_stolen_small: i64,
-    pub count_collected_big: i64,
-    pub count_stolen_big: i64,
-    pub count_collected_small: i64,
-    pub count_stolen_small: i64,
-    pub amount_overfill: i64,
-    pub amount_overfill_stolen: i64,
-    pub amount_used_while_supersonic: i64,
-    pub time_zero_boost: f64,
-    pub percent_zero_boost: f64,
-    pub time_full_boost: f64,
-    pub percent_full_boost: f64,
-    pub time_boost_0_to_25: f64,
-    pub time_boost_25_to_50: f64,
-    pub time_boost_50_to_75: f64,
-    pub time_boost_75_to_100: f64,
-    pub percent_boost_0_to_25: f64,
-    pub percent_boost_25_to_50: f64,
-    pub percent_boost_50_to_75: f64,
-    pub percent_boost_75_to_100: f64,
+response_type! {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct PlayerBoostStats {
+        pub bpm: i64,
+        pub bcpm: f64,
+        pub avg_amount: f64,
+        pub amount_collected: i64,
+        pub amount_stolen: i64,
+        pub amount_collected_big: i64,
+        pub amount_stolen_big: i64,
+        pub amount_collected_small: i64,
+        pub amount_stolen_small: i64,
+        pub count_collected_big: i64,
+        pub count_stolen_big: i64,
+        pub count_collected_small: i64,
+        pub count_stolen_small: i64,
+        pub amount_overfill: i64,
+        pub amount_overfill_stolen: i64,
+        pub amount_used_while_supersonic: i64,
+        pub time_zero_boost: f64,
+        pub percent_zero_boost: f64,
+        pub time_full_boost: f64,
+        pub percent_full_boost: f64,
+        pub time_boost_0_to_25: f64,
+        pub time_boost_25_to_50: f64,
+        pub time_boost_50_to_75: f64,
+        pub time_boost_75_to_100: f64,
+        pub percent_boost_0_to_25: f64,
+        pub percent_boost_25_to_50: f64,
+        pub percent_boost_50_to_75: f64,
+        pub percent_boost_75_to_100: f64,
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct PlayerMovementStats {
-    pub avg_speed: i64,
-    pub total_distance: i64,
-    pub time_supersonic_speed: f64,
-    pub time_boost_speed: f64,
-    pub time_slow_speed: f64,
-    pub time_ground: f64,
-    pub time_low_air: f64,
-    pub time_high_air: f64,
-    pub time_powerslide: f64,
-    pub count_powerslide: i64,
-    pub avg_powerslide_duration: f64,
-    pub avg_speed_percentage: f64,
-    pub percent_slow_speed: f64,
-    pub percent_boost_speed: f64,
-    pub percent_supersonic_speed: f64,
-    pub percent_ground: f64,
-    pub percent_low_air: f64,
-    pub percent_high_air: f64,
+response_type! {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct PlayerMovementStats {
+        pub avg_speed: i64,
+        pub total_distance: i64,
+        pub time_supersonic_speed: f64,
+        pub time_boost_speed: f64,
+        pub time_slow_speed: f64,
+        pub time_ground: f64,
+        pub time_low_air: f64,
+        pub time_high_air: f64,
+        pub time_powerslide: f64,
+        pub count_powerslide: i64,
+        pub avg_powerslide_duration: f64,
+        pub avg_speed_percentage: f64,
+        pub percent_slow_speed: f64,
+        pub percent_boost_speed: f64,
+        pub percent_supersonic_speed: f64,
+        pub percent_ground: f64,
+        pub percent_low_air: f64,
+        pub percent_high_air: f64,
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct PlayerPositioningStats {
-    pub avg_distance_to_ball: i64,
-    pub avg_distance_to_ball_possession: i64,
-    pub avg_distance_to_ball_no_possession: i64,
-    pub avg_distance_to_mates: i64,
-    pub time_defensive_third: f64,
-    pub time_neutral_third: f64,
-    pub time_offensive_third: f64,
-    pub time_defensive_half: f64,
-    pub time_offensive_half: f64,
-    pub time_behind_ball: f64,
-    pub time_infront_ball: f64,
-    pub time_most_back: f64,
-    pub time_most_forward: f64,
-    pub goals_against_while_last_defender: i64,
-    pub time_closest_to_ball: f64,
-    pub time_farthest_from_ball: f64,
-    pub percent_defensive_third: f64,
-    pub percent_offensive_third: f64,
-    pub percent_neutral_third: f64,
-    pub percent_defensive_half: f64,
-    pub percent_offensive_half: f64,
-    pub percent_behind_ball: f64,
-    pub percent_infront_ball: f64,
-    pub percent_most_back: f64,
-    pub percent_most_forward: f64,
-    pub percent_closest_to_ball: f64,
-    pub percent_farthest_from_ball: f64,
+response_type! {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct PlayerPositioningStats {
+        pub avg_distance_to_ball: i64,
+        pub avg_distance_to_ball_possession: i64,
+        pub avg_distance_to_ball_no_possession: i64,
+        pub avg_distance_to_mates: i64,
+        pub time_defensive_third: f64,
+        pub time_neutral_third: f64,
+        pub time_offensive_third: f64,
+        pub time_defensive_half: f64,
+        pub time_offensive_half: f64,
+        pub time_behind_ball: f64,
+        pub time_infront_ball: f64,
+        pub time_most_back: f64,
+        pub time_most_forward: f64,
+        pub goals_against_while_last_defender: i64,
+        pub time_closest_to_ball: f64,
+        pub time_farthest_from_ball: f64,
+        pub percent_defensive_third: f64,
+        pub percent_offensive_third: f64,
+        pub percent_neutral_third: f64,
+        pub percent_defensive_half: f64,
+        pub percent_offensive_half: f64,
+        pub percent_behind_ball: f64,
+        pub percent_infront_ball: f64,
+        pub percent_most_back: f64,
+        pub percent_most_forward: f64,
+        pub percent_closest_to_ball: f64,
+        pub percent_farthest_from_ball: f64,
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct AdvancedStats {
-    pub goal_participation: f64,
-    pub rating: Option<f64>,
-    #[serde(default)]
-    pub mvp: bool,
+response_type! {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct AdvancedStats {
+        pub goal_participation: f64,
+        pub rating: Option<f64>,
+        #[serde(default)]
+        pub mvp: bool,
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -421,24 +473,26 @@ pub struct GameScore<'a> {
     pub overtime: bool,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct Game<'a> {
-    #[serde(rename = "_id")]
-    pub id: GameId<'a>,
-    #[serde(rename = "octane_id")]
-    pub octane_id: Option<String>,
-    pub number: i64,
-    #[serde(rename = "match")]
-    pub match_field: Match<'a>,
-    pub map: Option<Map>,
-    pub duration: Option<i64>,
-    pub date: Option<DateTime<Utc>>,
-    pub blue: Side<'a>,
-    pub orange: Side<'a>,
-    pub ballchasing: Option<String>,
-    pub overtime: Option<bool>,
-    pub flip_ballchasing: Option<bool>,
+response_type! {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Game<'a> {
+        #[serde(rename = "_id")]
+        pub id: GameId<'a>,
+        #[serde(rename = "octane_id")]
+        pub octane_id: Option<String>,
+        pub number: i64,
+        #[serde(rename = "match")]
+        pub match_field: Match<'a>,
+        pub map: Option<Map>,
+        pub duration: Option<i64>,
+        pub date: Option<DateTime<Utc>>,
+        pub blue: Side<'a>,
+        pub orange: Side<'a>,
+        pub ballchasing: Option<String>,
+        pub overtime: Option<bool>,
+        pub flip_ballchasing: Option<bool>,
+    }
 }
 
 impl<'a> From<Game<'a>> for GameId<'a> {
@@ -455,11 +509,13 @@ pub struct Map {
     pub id: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct BallStats {
-    pub possession_time: f64,
-    pub time_in_side: f64,
+response_type! {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct BallStats {
+        pub possession_time: f64,
+        pub time_in_side: f64,
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -497,3 +553,177 @@ pub struct Participant<'a> {
     pub team: Team<'a>,
     pub players: Vec<Player<'a>>,
 }
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatCounts {
+    #[serde(default)]
+    pub total: i64,
+    #[serde(default)]
+    pub replays: i64,
+    #[serde(default)]
+    pub wins: i64,
+}
+
+impl StatCounts {
+    /// Scale a per-game `average` up to a total over the games with replay data
+    ///
+    /// The stats endpoints only return averages, so this is an approximation:
+    /// rounding in the average carries over into the total, and games without
+    /// replays are not counted.
+    pub fn total_from_average(&self, average: f64) -> f64 {
+        average * self.replays as f64
+    }
+}
+
+response_type! {
+    /// Aggregated stats for a player, returned by the player stats endpoints
+    ///
+    /// Depending on the endpoint, results are grouped by event, opponent or team.
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct PlayerStatline<'a> {
+        pub player: Player<'a>,
+        #[serde(default)]
+        pub events: Vec<Event<'a>>,
+        #[serde(default)]
+        pub teams: Vec<Team<'a>>,
+        #[serde(default)]
+        pub opponents: Vec<Team<'a>>,
+        pub start_date: Option<DateTime<Utc>>,
+        pub end_date: Option<DateTime<Utc>>,
+        #[serde(default)]
+        pub games: StatCounts,
+        #[serde(default)]
+        pub matches: StatCounts,
+        /// Per-game averages, keyed by stat name
+        #[serde(default)]
+        pub stats: BTreeMap<String, f64>,
+    }
+}
+
+impl PlayerStatline<'_> {
+    /// Per-game average of `stat`
+    pub fn average(&self, stat: impl AsRef<str>) -> Option<f64> {
+        self.stats.get(stat.as_ref()).copied()
+    }
+
+    /// Approximate total of `stat` over all games with replay data
+    ///
+    /// See [`StatCounts::total_from_average`].
+    pub fn total(&self, stat: impl AsRef<str>) -> Option<f64> {
+        self.average(stat)
+            .map(|avg| self.games.total_from_average(avg))
+    }
+}
+
+response_type! {
+    /// Aggregated stats for a team, returned by the team stats endpoints
+    ///
+    /// Depending on the endpoint, results are grouped by event or opponent.
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct TeamStatline<'a> {
+        pub team: Team<'a>,
+        #[serde(default)]
+        pub events: Vec<Event<'a>>,
+        #[serde(default)]
+        pub opponents: Vec<Team<'a>>,
+        pub start_date: Option<DateTime<Utc>>,
+        pub end_date: Option<DateTime<Utc>>,
+        #[serde(default)]
+        pub games: StatCounts,
+        #[serde(default)]
+        pub matches: StatCounts,
+        /// Per-game averages, keyed by stat name
+        #[serde(default)]
+        pub stats: BTreeMap<String, f64>,
+    }
+}
+
+impl TeamStatline<'_> {
+    /// Per-game average of `stat`
+    pub fn average(&self, stat: impl AsRef<str>) -> Option<f64> {
+        self.stats.get(stat.as_ref()).copied()
+    }
+
+    /// Approximate total of `stat` over all games with replay data
+    ///
+    /// See [`StatCounts::total_from_average`].
+    pub fn total(&self, stat: impl AsRef<str>) -> Option<f64> {
+        self.average(stat)
+            .map(|avg| self.games.total_from_average(avg))
+    }
+}
+
+#[cfg(all(test, feature = "extra-fields"))]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn extra_fields() {
+        let value = json!({
+            "_id": "6020bc70f1e4807cc70023c7",
+            "name": "G2 Esports",
+            "relevant": true,
+            "founded": 2014,
+        });
+        let team: Team<'_> = serde_json::from_value(value.clone()).unwrap();
+
+        assert_eq!(team.name, "G2 Esports");
+        assert_eq!(team.extra.get("founded"), Some(&json!(2014)));
+        assert_eq!(team.extra.len(), 1);
+        assert_eq!(serde_json::to_value(&team).unwrap()["founded"], 2014);
+    }
+
+    #[test]
+    fn nested_extra_fields() {
+        let value = json!({
+            "_id": "6082fb4c0d9dcf9da5a2d6a7",
+            "number": 1,
+            "match": {
+                "_id": "6043152fa09e7fba40d2ae62",
+                "slug": "ae62-g2-vs-nrg",
+                "event": {
+                    "_id": "5f35882d53fbbb5894b43040",
+                    "slug": "3040-rlcs-season-2-world-championship",
+                    "region": "INT",
+                    "mode": 3,
+                    "tier": "S",
+                    "sponsor": "Psyonix",
+                },
+                "stage": {"_id": 1, "name": "Playoffs"},
+                "bracket": "upper",
+            },
+            "blue": {"score": 3},
+            "orange": {"score": 2},
+            "replayVersion": 7,
+        });
+        let game: Game<'_> = serde_json::from_value(value).unwrap();
+
+        assert_eq!(game.extra.keys().collect::<Vec<_>>(), ["replayVersion"]);
+        assert_eq!(
+            game.match_field.extra.keys().collect::<Vec<_>>(),
+            ["bracket"]
+        );
+        assert_eq!(game.match_field.event.extra["sponsor"], "Psyonix");
+        assert!(game.match_field.stage.extra.is_empty());
+    }
+
+    #[test]
+    fn statline_extra_fields() {
+        let value = json!({
+            "player": {"_id": "5f3d8fdd95f40596eae23d97", "tag": "Kronovi"},
+            "games": {"total": 10, "replays": 8, "wins": 6},
+            "stats": {"score": 450.5},
+            "rank": 3,
+        });
+        let statline: PlayerStatline<'_> = serde_json::from_value(value).unwrap();
+
+        assert_eq!(statline.average("score"), Some(450.5));
+        assert_eq!(statline.extra.keys().collect::<Vec<_>>(), ["rank"]);
+        assert!(statline.player.extra.is_empty());
+    }
+}